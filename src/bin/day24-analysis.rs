@@ -1,15 +1,15 @@
-use days::day24::puzzle::ssa::Val;
+use days::y2021::day24::puzzle::ssa::Val;
 use fs::File;
 use itertools::Itertools;
 use std::io::prelude::*;
 use std::{fs, str};
 
-use days::day24;
+use days::y2021::day24;
 
 fn main() {
     use day24::puzzle::{emulate_sections, ssa, tree, ALU};
 
-    let bytes = fs::read("day24.txt").unwrap();
+    let bytes = fs::read("inputs/real/2021/day24.txt").unwrap();
     let s = str::from_utf8(&bytes).unwrap();
 
     let (_, instrs) = day24::puzzle::parser::instructions(&s).unwrap();
@@ -28,7 +28,7 @@ fn main() {
     digits.reverse();
     //let digits = vec![5,9,9,9,8,4,2,6,9,9,7,9,7,9];
     let mut alu = ALU::new();
-    alu.run(&instrs, digits.clone());
+    alu.run(&instrs, digits.clone()).unwrap();
     let alu_res = alu.z;
     let emu_res = emulate_sections(&digits, &params);
 
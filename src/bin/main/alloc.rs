@@ -0,0 +1,149 @@
+// Allocation accounting for `--alloc`. The runner always installs a counting
+// wrapper around the system allocator, but it only counts once enabled, so
+// normal runs and benches pay one relaxed load per allocation.
+//
+// Counts are kept per thread: a program runs on a single thread, so its
+// numbers stay its own when run-all runs several at once.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+pub struct CountingAlloc;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy)]
+struct Counts {
+    allocs: u64,
+    bytes: u64,
+    /// Bytes allocated minus bytes freed, negative when the thread frees
+    /// memory allocated before measuring started
+    live: i64,
+    peak: i64,
+}
+impl Counts {
+    const ZERO: Counts = Counts {
+        allocs: 0,
+        bytes: 0,
+        live: 0,
+        peak: 0,
+    };
+
+    fn allocated(&mut self, size: usize) {
+        self.allocs += 1;
+        self.bytes += size as u64;
+        self.live += size as i64;
+        self.peak = self.peak.max(self.live);
+    }
+
+    fn freed(&mut self, size: usize) {
+        self.live -= size as i64;
+    }
+}
+
+thread_local! {
+    // const and without a destructor, so it's safe to touch from the allocator
+    static COUNTS: Cell<Counts> = const { Cell::new(Counts::ZERO) };
+}
+
+fn record<F: FnOnce(&mut Counts)>(f: F) {
+    if ENABLED.load(Ordering::Relaxed) {
+        // the thread local may already be gone while a thread exits
+        let _ = COUNTS.try_with(|cell| {
+            let mut counts = cell.get();
+            f(&mut counts);
+            cell.set(counts);
+        });
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(|c| c.allocated(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(|c| c.allocated(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(|c| c.freed(layout.size()));
+    }
+
+    /// Counted as freeing the old block and allocating the new one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(|c| {
+                c.freed(layout.size());
+                c.allocated(new_size);
+            });
+        }
+        new_ptr
+    }
+}
+
+/// Allocations made while running one program
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AllocStats {
+    /// Allocations and reallocations
+    pub allocs: u64,
+    /// Total bytes requested
+    pub bytes: u64,
+    /// Most bytes held at once, counting only what was allocated while measuring
+    pub peak_bytes: u64,
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Run `f`, counting its allocations on this thread if accounting is enabled
+pub fn measure<R, F: FnOnce() -> R>(f: F) -> (R, Option<AllocStats>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+    COUNTS.with(|cell| cell.set(Counts::ZERO));
+    let result = f();
+    let counts = COUNTS.with(|cell| cell.get());
+    let stats = AllocStats {
+        allocs: counts.allocs,
+        bytes: counts.bytes,
+        peak_bytes: counts.peak.max(0) as u64,
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        enable();
+        let (_, stats) = measure(|| {
+            let mut v: Vec<u64> = Vec::with_capacity(100);
+            v.extend(0..100);
+            let w = vec![0u8; 1000];
+            drop(v);
+            w.len()
+        });
+        let stats = stats.unwrap();
+        assert_eq!(stats.allocs, 2);
+        assert_eq!(stats.bytes, 1800);
+        assert_eq!(stats.peak_bytes, 1800);
+
+        let (_, stats) = measure(|| vec![0u8; 10]);
+        assert_eq!(stats.map(|s| s.bytes), Some(10));
+    }
+}
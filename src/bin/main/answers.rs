@@ -0,0 +1,162 @@
+// Golden answers for an input set, stored as a flat TOML-ish file next to the
+// inputs, e.g. `inputs/real/answers.toml`:
+//
+//   # comment
+//   2021/day15-2 = 2821
+//   2021/day13-2 = "ECFHLHZF"
+//
+// Keys are program names; unqualified ones like `day15-2`, from before the
+// year was part of the name, are 2021 programs. Only unquoted keys, integers
+// and basic strings without escapes are understood, which is all we need to
+// guard against regressions. Answers are compared by their printed form.
+
+use days::solution::Answer;
+
+use std::collections::HashMap;
+use std::fs;
+
+pub const FILE_NAME: &str = "answers.toml";
+
+#[derive(Debug, PartialEq)]
+pub enum Check {
+    Pass,
+    Fail(String),
+    Unknown,
+}
+impl Check {
+    /// Compare an answer with an expected one by printed form
+    pub fn of(expected: Option<&str>, answer: &Answer) -> Check {
+        match expected {
+            Some(expected) if expected == answer.to_string() => Check::Pass,
+            Some(expected) => Check::Fail(expected.to_string()),
+            None => Check::Unknown,
+        }
+    }
+}
+
+pub struct Answers {
+    expected: HashMap<String, String>,
+}
+impl Answers {
+    pub fn empty() -> Answers {
+        Answers {
+            expected: HashMap::new(),
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Answers, String> {
+        let mut expected = HashMap::new();
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `name = answer`", i + 1))?;
+            let value = parse_value(value.trim()).map_err(|e| format!("line {}: {}", i + 1, e))?;
+            let name = match key.trim() {
+                name if name.contains('/') => name.to_string(),
+                legacy => format!("2021/{}", legacy),
+            };
+            expected.insert(name, value);
+        }
+        Ok(Answers { expected })
+    }
+
+    /// Load answers from `path`, treating a missing file as having no answers
+    pub fn load(path: &str) -> Result<Answers, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::parse(&contents).map_err(|e| format!("{}: {}", path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::empty()),
+            Err(e) => Err(format!("{}: {}", path, e)),
+        }
+    }
+
+    pub fn check(&self, name: &str, answer: &Answer) -> Check {
+        Check::of(self.expected.get(name).map(|s| s.as_str()), answer)
+    }
+}
+
+/// A quoted string or an integer, followed by an optional comment
+fn parse_value(value: &str) -> Result<String, String> {
+    let (value, rest) = match value.strip_prefix('"') {
+        Some(quoted) => {
+            let end = quoted.find('"').ok_or("unterminated string")?;
+            (quoted[..end].to_string(), &quoted[end + 1..])
+        }
+        None => {
+            let end = value.find('#').unwrap_or(value.len());
+            let number = value[..end].trim();
+            number
+                .parse::<i128>()
+                .map_err(|e| format!("{}: {}", number, e))?;
+            (number.to_string(), &value[end..])
+        }
+    };
+    match rest.trim() {
+        rest if rest.is_empty() || rest.starts_with('#') => Ok(value),
+        rest => Err(format!("unexpected {}", rest)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_check() {
+        let answers = Answers::parse(
+            r#"
+            # day 15
+            2021/day15-1 = 40
+            2021/day15-2=315 # expanded
+            2021/day13-2 = "ECFHLHZF" # letters
+            2021/day17-1 = -45
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers.check("2021/day15-1", &Answer::Integer(40)),
+            Check::Pass
+        );
+        assert_eq!(
+            answers.check("2021/day15-2", &Answer::Integer(316)),
+            Check::Fail("315".to_string())
+        );
+        assert_eq!(
+            answers.check("2021/day16-1", &Answer::Integer(1)),
+            Check::Unknown
+        );
+        assert_eq!(
+            answers.check("2021/day13-2", &Answer::Text("ECFHLHZF".to_string())),
+            Check::Pass
+        );
+        assert_eq!(
+            answers.check("2021/day17-1", &Answer::Signed(-45)),
+            Check::Pass
+        );
+    }
+
+    #[test]
+    fn test_legacy_keys() {
+        let answers = Answers::parse("day15-2 = 315\n").unwrap();
+        assert_eq!(
+            answers.check("2021/day15-2", &Answer::Integer(315)),
+            Check::Pass
+        );
+        assert_eq!(
+            answers.check("2021/day15-2", &Answer::Integer(316)),
+            Check::Fail("315".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("2021/day15-1 40").is_err());
+        assert!(Answers::parse("2021/day15-1 = forty").is_err());
+        assert!(Answers::parse("2021/day13-2 = \"ECF").is_err());
+        assert!(Answers::parse("2021/day13-2 = \"ECF\" H").is_err());
+    }
+}
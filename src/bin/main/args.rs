@@ -0,0 +1,208 @@
+use crate::bench::BenchOptions;
+use crate::history;
+use days::solution::DEFAULT_INPUT_SET;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+pub struct Options {
+    pub command: String,
+    pub operands: Vec<String>,
+    pub format: Format,
+    /// Input set under `inputs/`, used unless `input` or `stdin` is given
+    pub input_set: String,
+    pub input: Option<String>,
+    pub stdin: bool,
+    /// Explicit answers file, otherwise the input set's own
+    pub answers: Option<String>,
+    pub bench: BenchOptions,
+    pub history: String,
+    pub save_history: bool,
+    pub threshold: f64,
+    /// run-all: programs run at once
+    pub jobs: usize,
+    /// Solver diagnostics, e.g. `debug` or `info,y2021::day23=trace`
+    pub log: Option<String>,
+    pub log_file: Option<String>,
+    /// Run on the day's examples instead of its puzzle input
+    pub example: bool,
+    /// Count the allocations of each program
+    pub alloc: bool,
+}
+
+pub const USAGE: &str = "\
+usage: main [options] <YYYY/dayNN-P>
+       main [options] run-all
+       main [options] bench [YYYY/dayNN-P]
+       main [options] compare [baseline [current]]
+       main [options] repl <YYYY/dayNN>
+       main new-day [YEAR] <N>
+
+options:
+  --format <fmt>     text (default) or json, one object per line per day/part
+  --inputs <set>     read inputs from inputs/<set>/<year>/ (default real)
+  --input <file>     read the input of a single program from <file>
+  --stdin            read the input of a single program from stdin
+  --example          run on the examples from the puzzle description and
+                     show their expected answers
+  --answers <file>   golden answers to check against
+                     (default inputs/<set>/answers.toml, none for --input/--stdin)
+  --warmup <n>       bench: untimed runs before measuring (default 3)
+  --runs <n>         bench: measured runs (default 10)
+  --split            bench: time parsing and solving separately
+  --history <file>   bench history (default bench_history.csv)
+  --no-save          bench: don't append results to the history
+  --threshold <pct>  compare: slowdown flagged as a regression (default 10)
+  --jobs <n>         run-all: run up to n programs in parallel (default 1)
+  --alloc            count allocations, bytes allocated and peak live bytes
+                     of each program
+  --log <filter>     solver diagnostics on stderr, at a level (error, warn,
+                     info, debug, trace) or per year or day, e.g.
+                     info,y2021::day23=trace
+  --log-file <file>  write diagnostics to <file> instead (default level info)
+
+Programs can be named without the year (dayNN-P) while only one year has
+that day. compare selects runs by run id or commit prefix; by default the
+latest run is compared against the one before it. new-day generates the
+module for day N of YEAR (default the latest year), registers it and
+creates its empty input files. repl steps through the puzzle state of a
+day that supports it, reading commands from stdin; try help.";
+
+fn number<T: std::str::FromStr>(flag: &str, value: String) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a number, got {}", flag, value))
+}
+
+pub fn parse(args: &[String]) -> Result<Options, String> {
+    let mut positional = vec![];
+    let mut options = Options {
+        command: String::new(),
+        operands: vec![],
+        format: Format::Text,
+        input_set: DEFAULT_INPUT_SET.to_string(),
+        input: None,
+        stdin: false,
+        answers: None,
+        bench: BenchOptions {
+            warmup: 3,
+            runs: 10,
+            split: false,
+        },
+        history: history::DEFAULT_PATH.to_string(),
+        save_history: true,
+        threshold: 10.0,
+        jobs: 1,
+        log: None,
+        log_file: None,
+        example: false,
+        alloc: false,
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = |flag: &str| {
+            iter.next()
+                .cloned()
+                .ok_or_else(|| format!("{} expects a value", flag))
+        };
+        match arg.as_str() {
+            "--format" => {
+                options.format = match value(arg)?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    other => return Err(format!("unknown format {}", other)),
+                }
+            }
+            "--inputs" => options.input_set = value(arg)?,
+            "--input" => options.input = Some(value(arg)?),
+            "--stdin" => options.stdin = true,
+            "--example" => options.example = true,
+            "--alloc" => options.alloc = true,
+            "--answers" => options.answers = Some(value(arg)?),
+            "--warmup" => options.bench.warmup = number(arg, value(arg)?)?,
+            "--runs" => options.bench.runs = number(arg, value(arg)?)?,
+            "--split" => options.bench.split = true,
+            "--history" => options.history = value(arg)?,
+            "--no-save" => options.save_history = false,
+            "--threshold" => options.threshold = number(arg, value(arg)?)?,
+            "--jobs" => options.jobs = number(arg, value(arg)?)?,
+            "--log" => options.log = Some(value(arg)?),
+            "--log-file" => options.log_file = Some(value(arg)?),
+            flag if flag.starts_with("--") => return Err(format!("unknown option {}", flag)),
+            _ => positional.push(arg.clone()),
+        }
+    }
+    if options.bench.runs == 0 {
+        return Err("--runs must be at least 1".to_string());
+    }
+    if options.jobs == 0 {
+        return Err("--jobs must be at least 1".to_string());
+    }
+
+    let mut positional = positional.into_iter();
+    options.command = positional.next().ok_or("missing command")?;
+    options.operands = positional.collect();
+    let max_operands = match options.command.as_str() {
+        "bench" => 1,
+        "compare" => 2,
+        "new-day" => 2,
+        "repl" => 1,
+        _ => 0,
+    };
+    if let Some(extra) = options.operands.get(max_operands) {
+        return Err(format!("unexpected argument {}", extra));
+    }
+
+    if options.command == "new-day" && options.operands.is_empty() {
+        return Err("new-day needs a day number".to_string());
+    }
+    if options.command == "repl" {
+        if options.operands.is_empty() {
+            return Err("repl needs a day, e.g. 2021/day23".to_string());
+        }
+        if options.stdin {
+            return Err("repl reads its commands from stdin, use --input".to_string());
+        }
+        if options.format == Format::Json {
+            return Err("repl only supports text output".to_string());
+        }
+    }
+
+    if options.alloc && ["bench", "compare", "new-day", "repl"].contains(&options.command.as_str())
+    {
+        return Err(format!("--alloc doesn't apply to {}", options.command));
+    }
+
+    if options.command == "compare" && options.format == Format::Json {
+        return Err("compare only supports text output".to_string());
+    }
+
+    if options.example {
+        if options.input.is_some() || options.stdin {
+            return Err("--example can't be combined with --input or --stdin".to_string());
+        }
+        if ["bench", "compare", "new-day"].contains(&options.command.as_str()) {
+            return Err(format!("--example doesn't apply to {}", options.command));
+        }
+    }
+
+    if options.input.is_some() || options.stdin {
+        if options.input.is_some() && options.stdin {
+            return Err("--input and --stdin can't be combined".to_string());
+        }
+        let single = match options.command.as_str() {
+            "run-all" | "compare" | "new-day" => false,
+            "bench" => !options.operands.is_empty(),
+            _ => true,
+        };
+        if !single {
+            return Err("--input and --stdin need a single program".to_string());
+        }
+    }
+
+    Ok(options)
+}
@@ -0,0 +1,109 @@
+// Repeated timing of day/parts. Each program is run `warmup` times untimed,
+// then `runs` times measured. With `split` the day's parser is timed on its
+// own before every measured run and solve time is the remainder.
+
+use crate::run::{read_input, timed_call, InputSource};
+use crate::stats::Stats;
+use days::solution::{Answer, Parse, Program};
+
+use std::panic;
+use std::time::{Duration, Instant};
+
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub runs: usize,
+    pub split: bool,
+}
+
+pub struct BenchResult {
+    pub name: String,
+    pub year: u32,
+    pub day: u32,
+    pub part: usize,
+    pub outcome: Result<Answer, String>,
+    pub runs: usize,
+    pub total: Option<Stats>,
+    pub parse: Option<Stats>,
+    pub solve: Option<Stats>,
+}
+impl BenchResult {
+    pub fn failed(&self) -> bool {
+        self.outcome.is_err()
+    }
+}
+
+fn timed_parse(parse: Parse, input: &str) -> Result<Duration, String> {
+    let start = Instant::now();
+    let parsed = panic::catch_unwind(|| parse(input));
+    let elapsed = start.elapsed();
+    match parsed {
+        Ok(Ok(())) => Ok(elapsed),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Err("parser panicked".to_string()),
+    }
+}
+
+pub fn bench_program(
+    program: &Program,
+    source: &InputSource,
+    options: &BenchOptions,
+) -> BenchResult {
+    let mut result = BenchResult {
+        name: program.name.clone(),
+        year: program.year,
+        day: program.day,
+        part: program.part,
+        outcome: Err(String::new()),
+        runs: options.runs,
+        total: None,
+        parse: None,
+        solve: None,
+    };
+
+    let input = match read_input(&program.input, source) {
+        Ok(input) => input,
+        Err(e) => {
+            result.outcome = Err(e);
+            return result;
+        }
+    };
+
+    for _ in 0..options.warmup {
+        let (outcome, _) = timed_call(program.entry, &input);
+        if outcome.is_err() {
+            result.outcome = outcome;
+            return result;
+        }
+    }
+
+    let parse = program.parse.filter(|_| options.split);
+    let mut totals = Vec::with_capacity(options.runs);
+    let mut parses = Vec::with_capacity(options.runs);
+    let mut solves = Vec::with_capacity(options.runs);
+    for _ in 0..options.runs {
+        if let Some(parse) = parse {
+            match timed_parse(parse, &input) {
+                Ok(elapsed) => parses.push(elapsed),
+                Err(e) => {
+                    result.outcome = Err(e);
+                    return result;
+                }
+            }
+        }
+
+        let (outcome, elapsed) = timed_call(program.entry, &input);
+        result.outcome = outcome;
+        if result.outcome.is_err() {
+            return result;
+        }
+        totals.push(elapsed);
+        if let Some(parsed) = parses.last() {
+            solves.push(elapsed.saturating_sub(*parsed));
+        }
+    }
+
+    result.total = Stats::of(&totals);
+    result.parse = Stats::of(&parses);
+    result.solve = Stats::of(&solves);
+    result
+}
@@ -0,0 +1,253 @@
+// Benchmark history, appended to a local CSV file after every bench so
+// performance work can be compared across commits. One row per day/part:
+//
+//   run,commit,name,runs,min_ns,median_ns,mean_ns,stddev_ns
+//
+// `run` is the bench start time in unix milliseconds and groups the rows of
+// one invocation. Rows written before programs had a year name them without
+// one; they're read as 2021, the only year there was.
+
+use crate::bench::BenchResult;
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_PATH: &str = "bench_history.csv";
+const HEADER: &str = "run,commit,name,runs,min_ns,median_ns,mean_ns,stddev_ns";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub run: u128,
+    pub commit: String,
+    pub name: String,
+    pub runs: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: u128,
+    pub stddev_ns: u128,
+}
+impl Record {
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            self.run,
+            self.commit,
+            self.name,
+            self.runs,
+            self.min_ns,
+            self.median_ns,
+            self.mean_ns,
+            self.stddev_ns
+        )
+    }
+
+    fn parse(line: &str) -> Option<Record> {
+        let fields: Vec<&str> = line.trim().split(',').collect();
+        if fields.len() != 8 {
+            return None;
+        }
+        Some(Record {
+            run: fields[0].parse().ok()?,
+            commit: fields[1].to_string(),
+            name: match fields[2] {
+                name if name.contains('/') => name.to_string(),
+                legacy => format!("2021/{}", legacy),
+            },
+            runs: fields[3].parse().ok()?,
+            min_ns: fields[4].parse().ok()?,
+            median_ns: fields[5].parse().ok()?,
+            mean_ns: fields[6].parse().ok()?,
+            stddev_ns: fields[7].parse().ok()?,
+        })
+    }
+}
+
+/// Short hash of HEAD, suffixed with `-dirty` for uncommitted changes
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+            .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => format!("{}-dirty", hash),
+            _ => hash,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+pub fn records_of(results: &[BenchResult], commit: &str) -> Vec<Record> {
+    let run = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    results
+        .iter()
+        .filter_map(|result| {
+            let total = result.total.as_ref()?;
+            Some(Record {
+                run,
+                commit: commit.to_string(),
+                name: result.name.clone(),
+                runs: result.runs,
+                min_ns: total.min.as_nanos(),
+                median_ns: total.median.as_nanos(),
+                mean_ns: total.mean.as_nanos(),
+                stddev_ns: total.stddev.as_nanos(),
+            })
+        })
+        .collect()
+}
+
+pub fn append(path: &str, records: &[Record]) -> std::io::Result<()> {
+    let exists = fs::metadata(path).is_ok();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if !exists {
+        writeln!(file, "{}", HEADER)?;
+    }
+    for record in records {
+        writeln!(file, "{}", record.to_csv())?;
+    }
+    Ok(())
+}
+
+pub fn parse(input: &str) -> Result<Vec<Record>, String> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && line.trim() != HEADER)
+        .map(|(i, line)| Record::parse(line).ok_or_else(|| format!("line {}: malformed", i + 1)))
+        .collect()
+}
+
+pub fn load(path: &str) -> Result<Vec<Record>, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    parse(&contents).map_err(|e| format!("{}: {}", path, e))
+}
+
+/// Run ids in the order they were recorded
+fn runs(records: &[Record]) -> Vec<u128> {
+    let mut runs: Vec<u128> = records.iter().map(|r| r.run).collect();
+    runs.sort_unstable();
+    runs.dedup();
+    runs
+}
+
+/// Resolve a run by id or commit prefix (latest matching run wins).
+/// Without a spec, `back` selects the run that many places from the latest.
+pub fn select_run(records: &[Record], spec: Option<&str>, back: usize) -> Result<u128, String> {
+    let runs = runs(records);
+    match spec {
+        Some(spec) => records
+            .iter()
+            .filter(|r| r.run.to_string() == spec || r.commit.starts_with(spec))
+            .map(|r| r.run)
+            .max()
+            .ok_or_else(|| format!("no bench run matching {}", spec)),
+        None => runs
+            .len()
+            .checked_sub(back + 1)
+            .map(|i| runs[i])
+            .ok_or_else(|| format!("need at least {} bench runs in history", back + 1)),
+    }
+}
+
+pub struct Comparison {
+    pub name: String,
+    pub baseline_ns: u128,
+    pub current_ns: u128,
+    /// Percentage change of the median, positive is slower
+    pub change: f64,
+    pub regressed: bool,
+}
+
+pub fn compare(
+    records: &[Record],
+    baseline: u128,
+    current: u128,
+    threshold: f64,
+) -> Vec<Comparison> {
+    records
+        .iter()
+        .filter(|r| r.run == current)
+        .filter_map(|cur| {
+            let base = records
+                .iter()
+                .find(|r| r.run == baseline && r.name == cur.name)?;
+            let change = (cur.median_ns as f64 - base.median_ns as f64)
+                / base.median_ns.max(1) as f64
+                * 100.0;
+            Some(Comparison {
+                name: cur.name.clone(),
+                baseline_ns: base.median_ns,
+                current_ns: cur.median_ns,
+                change,
+                regressed: change > threshold,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(run: u128, commit: &str, name: &str, median_ns: u128) -> Record {
+        Record {
+            run,
+            commit: commit.to_string(),
+            name: name.to_string(),
+            runs: 10,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            stddev_ns: 0,
+        }
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let r = record(1, "abc123", "2021/day15-2", 2000);
+        let input = format!("{}\n{}\n", HEADER, r.to_csv());
+        assert_eq!(parse(&input), Ok(vec![r]));
+        assert!(parse("1,abc,2021/day15-2").is_err());
+
+        let legacy = parse("1,abc123,day15-2,10,2000,2000,2000,0").unwrap();
+        assert_eq!(legacy, vec![record(1, "abc123", "2021/day15-2", 2000)]);
+    }
+
+    #[test]
+    fn test_select_and_compare() {
+        let records = vec![
+            record(1, "aaa", "2021/day15-1", 1000),
+            record(1, "aaa", "2021/day15-2", 1000),
+            record(2, "bbb", "2021/day15-1", 1050),
+            record(2, "bbb", "2021/day15-2", 1200),
+            record(2, "bbb", "2021/day16-1", 10),
+        ];
+
+        assert_eq!(select_run(&records, None, 0), Ok(2));
+        assert_eq!(select_run(&records, None, 1), Ok(1));
+        assert!(select_run(&records, None, 2).is_err());
+        assert_eq!(select_run(&records, Some("aa"), 0), Ok(1));
+        assert_eq!(select_run(&records, Some("2"), 0), Ok(2));
+
+        let comparisons = compare(&records, 1, 2, 10.0);
+        let summary: Vec<(&str, bool)> = comparisons
+            .iter()
+            .map(|c| (&c.name[..], c.regressed))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("2021/day15-1", false), ("2021/day15-2", true)]
+        );
+        assert!((comparisons[1].change - 20.0).abs() < 1e-9);
+    }
+}
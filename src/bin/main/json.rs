@@ -0,0 +1,132 @@
+// Just enough JSON to report results with `--format json`. Results are
+// written as JSON Lines, one object per day/part, so scripts can consume
+// them as a stream.
+
+use days::solution::Answer;
+
+pub enum Value {
+    Null,
+    /// An integer, kept in its printed form so any width renders exactly
+    Number(String),
+    String(String),
+}
+impl From<u128> for Value {
+    fn from(n: u128) -> Value {
+        Value::Number(n.to_string())
+    }
+}
+impl From<u32> for Value {
+    fn from(n: u32) -> Value {
+        Value::Number(n.to_string())
+    }
+}
+impl From<u64> for Value {
+    fn from(n: u64) -> Value {
+        Value::Number(n.to_string())
+    }
+}
+impl From<usize> for Value {
+    fn from(n: usize) -> Value {
+        Value::Number(n.to_string())
+    }
+}
+/// Numeric answers become numbers, text and drawings become strings
+impl From<&Answer> for Value {
+    fn from(answer: &Answer) -> Value {
+        match answer {
+            Answer::Integer(n) => Value::Number(n.to_string()),
+            Answer::Signed(n) => Value::Number(n.to_string()),
+            Answer::Text(_) | Answer::Lines(_) => Value::String(answer.to_string()),
+        }
+    }
+}
+impl From<&str> for Value {
+    fn from(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+}
+impl From<String> for Value {
+    fn from(s: String) -> Value {
+        Value::String(s)
+    }
+}
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Value {
+        value.map_or(Value::Null, Into::into)
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// A flat object, rendered with its fields in insertion order
+#[derive(Default)]
+pub struct Object {
+    fields: Vec<(&'static str, Value)>,
+}
+impl Object {
+    pub fn new() -> Object {
+        Object::default()
+    }
+
+    pub fn field<V: Into<Value>>(mut self, key: &'static str, value: V) -> Object {
+        self.fields.push((key, value.into()));
+        self
+    }
+
+    pub fn render(&self) -> String {
+        let fields: Vec<String> = self
+            .fields
+            .iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    Value::Null => "null".to_string(),
+                    Value::Number(n) => n.clone(),
+                    Value::String(s) => escape(s),
+                };
+                format!("{}:{}", escape(key), value)
+            })
+            .collect();
+        format!("{{{}}}", fields.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let object = Object::new()
+            .field("name", "day13-2")
+            .field("answer", None::<&Answer>)
+            .field("time_ns", 1500u128)
+            .field("error", "line 1:\t\"x\"\n\u{1}");
+        assert_eq!(
+            object.render(),
+            r#"{"name":"day13-2","answer":null,"time_ns":1500,"error":"line 1:\t\"x\"\n\u0001"}"#
+        );
+        assert_eq!(Object::new().render(), "{}");
+
+        let answers = Object::new().field("a", &Answer::Signed(-3)).field(
+            "b",
+            &Answer::Lines(vec!["#.".to_string(), ".#".to_string()]),
+        );
+        assert_eq!(answers.render(), r##"{"a":-3,"b":"#.\n.#"}"##);
+    }
+}
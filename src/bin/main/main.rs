@@ -0,0 +1,236 @@
+mod alloc;
+mod answers;
+mod args;
+mod bench;
+mod history;
+mod json;
+mod pool;
+mod repl;
+mod report;
+mod run;
+mod scaffold;
+mod stats;
+
+use answers::Answers;
+use args::Format;
+use days::solution::{input_set_path, registry, Program};
+use days::util::log;
+use run::InputSource;
+
+use std::env;
+use std::fs::File;
+use std::io::{self, IsTerminal};
+use std::path::Path;
+use std::process;
+use std::time::Instant;
+
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAlloc = alloc::CountingAlloc;
+
+fn usage(error: &str) -> ! {
+    eprintln!("{}\n{}\nprograms:", error, args::USAGE);
+    for program in registry::programs() {
+        eprintln!("  {}", program.name);
+    }
+    process::exit(2);
+}
+
+fn find_program(name: &str) -> Program {
+    registry::find(name).unwrap_or_else(|| usage(&format!("unknown program {}", name)))
+}
+
+fn fatal(error: &str) -> ! {
+    eprintln!("{}", error);
+    process::exit(2);
+}
+
+fn input_source(options: &args::Options) -> Result<InputSource, String> {
+    if options.stdin {
+        InputSource::stdin()
+    } else if let Some(path) = &options.input {
+        Ok(InputSource::File(path.clone()))
+    } else {
+        Ok(InputSource::Set(options.input_set.clone()))
+    }
+}
+
+/// Answers for the chosen input set. An explicit input has no known answers
+/// unless an answers file is named.
+fn load_answers(options: &args::Options) -> Result<Answers, String> {
+    match &options.answers {
+        Some(path) => Answers::load(path),
+        None if options.input.is_some() || options.stdin => Ok(Answers::empty()),
+        None => {
+            let path = input_set_path(&options.input_set, answers::FILE_NAME);
+            Answers::load(&path.to_string_lossy())
+        }
+    }
+}
+
+/// Solvers stay silent unless diagnostics were asked for
+fn init_logging(options: &args::Options) -> Result<(), String> {
+    if options.log.is_none() && options.log_file.is_none() {
+        return Ok(());
+    }
+    let filter = log::Filter::parse(options.log.as_deref().unwrap_or("info"))?;
+    let sink = match &options.log_file {
+        Some(path) => log::Sink::File(
+            File::create(path).map_err(|e| format!("can't create {}: {}", path, e))?,
+        ),
+        None => log::Sink::Stderr,
+    };
+    log::init(filter, sink);
+    Ok(())
+}
+
+/// Run programs on their day's examples, returning whether any failed
+fn run_examples(options: &args::Options) -> bool {
+    let programs = match options.command.as_str() {
+        "run-all" => registry::programs(),
+        name => vec![find_program(name)],
+    };
+    let results: Vec<run::RunResult> = pool::map(&programs, options.jobs, run::run_examples)
+        .into_iter()
+        .flatten()
+        .collect();
+    match options.format {
+        Format::Text => report::print_examples(&results),
+        Format::Json => report::print_json_runs(&results),
+    }
+    results.iter().any(|r| r.failed())
+}
+
+fn new_day(options: &args::Options) -> Result<(), String> {
+    let number = |operand: &String, what: &str| {
+        operand
+            .parse()
+            .map_err(|_| format!("new-day expects a {} number, got {}", what, operand))
+    };
+    let (year, day) = match options.operands.as_slice() {
+        [day] => (None, number(day, "day")?),
+        [year, day] => (Some(number(year, "year")?), number(day, "day")?),
+        _ => return Err("new-day expects [YEAR] <N>".to_string()),
+    };
+    let written = scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), year, day)?;
+    for path in written {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+/// Interactive session on a day's puzzle input or, with `--example`, its
+/// first example
+fn repl(options: &args::Options, source: &InputSource) -> Result<(), String> {
+    let name = &options.operands[0];
+    let solution = registry::find_solution(name).ok_or_else(|| format!("unknown day {}", name))?;
+    let start = solution
+        .session()
+        .ok_or_else(|| format!("{} has no interactive session", solution.name()))?;
+    let text = if options.example {
+        let example = solution.examples().first();
+        example
+            .map(|example| example.input.to_string())
+            .ok_or_else(|| format!("{} has no examples", solution.name()))?
+    } else {
+        run::read_input(&solution.input_path(), source)?
+    };
+    let stdin = io::stdin();
+    let prompt = stdin.is_terminal();
+    repl::run(start, &text, stdin.lock(), io::stdout(), prompt)
+}
+
+/// Compare two bench runs from the history, returning whether any regressed
+fn compare(options: &args::Options) -> Result<bool, String> {
+    let records = history::load(&options.history)?;
+    let baseline_spec = options.operands.first().map(|s| s.as_str());
+    let current_spec = options.operands.get(1).map(|s| s.as_str());
+
+    let current = history::select_run(&records, current_spec, 0)?;
+    let baseline = match baseline_spec {
+        Some(_) => history::select_run(&records, baseline_spec, 0)?,
+        None => history::select_run(&records, None, 1)?,
+    };
+    let first_of = |run: u128| records.iter().find(|r| r.run == run).unwrap();
+
+    let comparisons = history::compare(&records, baseline, current, options.threshold);
+    report::print_compare(
+        &comparisons,
+        first_of(baseline),
+        first_of(current),
+        options.threshold,
+    );
+    Ok(comparisons.iter().any(|c| c.regressed))
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = args::parse(&args).unwrap_or_else(|e| usage(&e));
+    init_logging(&options).unwrap_or_else(|e| fatal(&e));
+    if options.alloc {
+        alloc::enable();
+    }
+    let answers = load_answers(&options).unwrap_or_else(|e| fatal(&e));
+    let source = input_source(&options).unwrap_or_else(|e| fatal(&e));
+
+    let failed = match options.command.as_str() {
+        "repl" => {
+            repl(&options, &source).unwrap_or_else(|e| fatal(&e));
+            false
+        }
+        _ if options.example => run_examples(&options),
+        "run-all" => {
+            let start = Instant::now();
+            let mut results = pool::map(&registry::programs(), options.jobs, |program| {
+                run::run_program(program, &source)
+            });
+            let wall = start.elapsed();
+            for result in &mut results {
+                result.check_against(&answers);
+            }
+            match options.format {
+                Format::Text => report::print_table(&results, wall),
+                Format::Json => report::print_json_runs(&results),
+            }
+            results.iter().any(|r| r.failed())
+        }
+        "bench" => {
+            let programs = match options.operands.first() {
+                Some(name) => vec![find_program(name)],
+                None => registry::programs(),
+            };
+            let results: Vec<bench::BenchResult> = programs
+                .iter()
+                .map(|program| bench::bench_program(program, &source, &options.bench))
+                .collect();
+            match options.format {
+                Format::Text => report::print_bench(&results, &options.bench),
+                Format::Json => report::print_json_bench(&results),
+            }
+            if options.save_history {
+                let records = history::records_of(&results, &history::current_commit());
+                if let Err(e) = history::append(&options.history, &records) {
+                    eprintln!("can't save bench history to {}: {}", options.history, e);
+                }
+            }
+            results.iter().any(|r| r.failed())
+        }
+        "compare" => compare(&options).unwrap_or_else(|e| fatal(&e)),
+        "new-day" => {
+            new_day(&options).unwrap_or_else(|e| fatal(&e));
+            false
+        }
+        name => {
+            let mut result = run::run_program(&find_program(name), &source);
+            result.check_against(&answers);
+            match options.format {
+                Format::Text => report::print_single(&result),
+                Format::Json => report::print_json_runs(std::slice::from_ref(&result)),
+            }
+            result.failed()
+        }
+    };
+
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,61 @@
+// A small worker pool on std threads for running independent programs at
+// once. Workers pull the next task off a shared counter, so a slow day only
+// holds up its own worker while the rest carry on with the queue.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Apply `f` to every task on up to `workers` threads. Results come back in
+/// the order of `tasks`, however the work was interleaved.
+pub fn map<T, R, F>(tasks: &[T], workers: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = workers.clamp(1, tasks.len().max(1));
+    if workers == 1 {
+        return tasks.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(tasks.iter().map(|_| None).collect());
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(task) = tasks.get(i) else { break };
+                let result = f(task);
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every task has run"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let tasks: Vec<u64> = (0..20).collect();
+        // later tasks finish first
+        let slow_first = |n: &u64| {
+            thread::sleep(Duration::from_millis(20 - n));
+            n * 2
+        };
+        let expected: Vec<u64> = tasks.iter().map(|n| n * 2).collect();
+        assert_eq!(map(&tasks, 4, slow_first), expected);
+        assert_eq!(map(&tasks, 1, slow_first), expected);
+        assert_eq!(map(&tasks, 100, slow_first), expected);
+        assert_eq!(map(&[] as &[u64], 4, slow_first), vec![]);
+    }
+}
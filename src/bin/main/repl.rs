@@ -0,0 +1,141 @@
+// `repl <YYYY/dayNN>`: an interactive session on a day's parsed input, for
+// days that offer one. Commands are read a line at a time; besides the day's
+// own there are a few that work on every session.
+
+use days::session::{Session, Start};
+
+use std::io::{BufRead, Write};
+
+const BUILTINS: &[(&str, &str)] = &[
+    ("show", "print the current state"),
+    ("reset", "start over from the input"),
+    ("help", "list the commands"),
+    ("quit", "leave, as does the end of input"),
+];
+
+fn help(session: &dyn Session) -> String {
+    let day = session.commands().iter().map(|command| {
+        let usage = format!("{} {}", command.name, command.args);
+        (usage.trim_end().to_string(), command.help)
+    });
+    let builtins = BUILTINS
+        .iter()
+        .map(|(name, help)| (name.to_string(), *help));
+    day.chain(builtins)
+        .map(|(usage, help)| format!("  {:<10} {}", usage, help))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Run the commands read from `input` on a session started from the puzzle
+/// `text`, writing what they print to `output`. With `prompt`, as when a
+/// person is typing, each command is prompted for.
+pub fn run<R: BufRead, W: Write>(
+    start: Start,
+    text: &str,
+    input: R,
+    mut output: W,
+    prompt: bool,
+) -> Result<(), String> {
+    let mut session = start(text).map_err(|e| e.to_string())?;
+    let io_error = |e: std::io::Error| format!("repl: {}", e);
+    if prompt {
+        writeln!(output, "loaded, try help").map_err(io_error)?;
+    }
+    let mut lines = input.lines();
+    loop {
+        if prompt {
+            write!(output, "> ").map_err(io_error)?;
+            output.flush().map_err(io_error)?;
+        }
+        let Some(line) = lines.next() else { break };
+        let line = line.map_err(io_error)?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((command, args)) = words.split_first() else {
+            continue;
+        };
+        let reply = match *command {
+            "quit" | "exit" => break,
+            "help" => Ok(help(session.as_ref())),
+            "show" => Ok(session.show()),
+            "reset" => start(text).map_err(|e| e.to_string()).map(|fresh| {
+                session = fresh;
+                session.show()
+            }),
+            _ => session.run(command, args),
+        };
+        match reply {
+            Ok(reply) => writeln!(output, "{}", reply),
+            Err(e) => writeln!(output, "error: {}", e),
+        }
+        .map_err(io_error)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use days::session::{self, Command};
+
+    /// Counts up, to check the loop without depending on a day
+    struct Counter(usize);
+    impl Session for Counter {
+        fn commands(&self) -> &'static [Command] {
+            &[Command {
+                name: "add",
+                args: "[N]",
+                help: "count N more",
+            }]
+        }
+
+        fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+            match command {
+                "add" => {
+                    self.0 += session::count(args)?;
+                    Ok(self.show())
+                }
+                _ => Err(session::unknown(command)),
+            }
+        }
+
+        fn show(&self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    fn counter(text: &str) -> days::Result<Box<dyn Session>> {
+        Ok(Box::new(Counter(text.trim().parse().unwrap())))
+    }
+
+    #[test]
+    fn test_run() {
+        let commands = "add\n\n  add 3\nadd x\nreset\nnope\nshow\nquit\nadd\n";
+        let mut output = vec![];
+        run(counter, "10", commands.as_bytes(), &mut output, false).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "11\n14\nerror: expected a count, got x\n10\n\
+             error: unknown command nope, try help\n10\n"
+        );
+    }
+
+    #[test]
+    fn test_help() {
+        let help = help(&Counter(0));
+        let lines: Vec<&str> = help.lines().collect();
+        assert_eq!(lines.len(), 1 + BUILTINS.len());
+        assert_eq!(lines[0], "  add [N]    count N more");
+        assert_eq!(lines[1], "  show       print the current state");
+    }
+
+    #[test]
+    fn test_prompt() {
+        let mut output = vec![];
+        run(counter, "1", "add\n".as_bytes(), &mut output, true).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "loaded, try help\n> 2\n> "
+        );
+    }
+}
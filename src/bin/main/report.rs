@@ -0,0 +1,301 @@
+use crate::alloc::AllocStats;
+use crate::answers::Check;
+use crate::bench::{BenchOptions, BenchResult};
+use crate::history::{Comparison, Record};
+use crate::json::Object;
+use crate::run::RunResult;
+use crate::stats::Stats;
+use days::solution::Answer;
+
+use std::time::Duration;
+
+fn check_label(check: &Check) -> String {
+    match check {
+        Check::Pass => "pass".to_string(),
+        Check::Fail(expected) => format!("FAIL (expected {})", expected),
+        Check::Unknown => "unknown".to_string(),
+    }
+}
+
+/// Answers on one line, drawings are summarized
+fn answer_label(answer: &Answer) -> String {
+    match answer {
+        Answer::Lines(lines) => format!("<{}-line drawing>", lines.len()),
+        answer => answer.to_string(),
+    }
+}
+
+/// Allocation columns, empty unless accounting was enabled
+fn alloc_columns(allocs: &Option<AllocStats>) -> String {
+    match allocs {
+        Some(a) => format!(" {:>10} {:>14} {:>14}", a.allocs, a.bytes, a.peak_bytes),
+        None => String::new(),
+    }
+}
+
+fn alloc_header(results: &[RunResult]) -> String {
+    if results.iter().any(|r| r.allocs.is_some()) {
+        format!(" {:>10} {:>14} {:>14}", "allocs", "bytes", "peak bytes")
+    } else {
+        String::new()
+    }
+}
+
+pub fn print_single(result: &RunResult) {
+    match &result.outcome {
+        Ok(Answer::Lines(lines)) => {
+            println!("{}: [{}]", result.name, check_label(&result.check));
+            for line in lines {
+                println!("{}", line);
+            }
+        }
+        Ok(answer) => println!(
+            "{}: {} [{}]",
+            result.name,
+            answer,
+            check_label(&result.check)
+        ),
+        Err(e) => println!("{}: failed ({})", result.name, e),
+    }
+    println!("took: {:?}", result.elapsed);
+    if let Some(a) = &result.allocs {
+        println!(
+            "allocated: {} allocations, {} bytes, peak {} bytes",
+            a.allocs, a.bytes, a.peak_bytes
+        );
+    }
+}
+
+/// The total row sums the time of each program; `wall` is how long the whole
+/// run took, which is shorter when programs ran in parallel
+pub fn print_table(results: &[RunResult], wall: Duration) {
+    println!(
+        "{:<11} {:>4}  {:<24} {:<28} {:>12}{}",
+        "day",
+        "part",
+        "answer",
+        "check",
+        "time",
+        alloc_header(results)
+    );
+    for result in results {
+        let (answer, check) = match &result.outcome {
+            Ok(answer) => (answer_label(answer), check_label(&result.check)),
+            Err(e) => (format!("FAILED: {}", e), "-".to_string()),
+        };
+        println!(
+            "{:<11} {:>4}  {:<24} {:<28} {:>12}{}",
+            format!("{}/day{}", result.year, result.day),
+            result.part,
+            answer,
+            check,
+            format!("{:?}", result.elapsed),
+            alloc_columns(&result.allocs)
+        );
+    }
+
+    let total: Duration = results.iter().map(|r| r.elapsed).sum();
+    let count = |check: Check| results.iter().filter(|r| r.check == check).count();
+    let failed = results.iter().filter(|r| r.failed()).count();
+    println!(
+        "{:<11} {:>4}  {:<24} {:<28} {:>12}",
+        "total",
+        results.len(),
+        format!("{} failed", failed),
+        format!(
+            "{} pass, {} unknown",
+            count(Check::Pass),
+            count(Check::Unknown)
+        ),
+        format!("{:?}", total)
+    );
+    println!("wall time: {:?}", wall);
+}
+
+/// Example runs with the expected answer next to the actual one
+pub fn print_examples(results: &[RunResult]) {
+    println!(
+        "{:<11} {:>4} {:>7}  {:<20} {:<20} {:<7} {:>12}{}",
+        "day",
+        "part",
+        "example",
+        "answer",
+        "expected",
+        "check",
+        "time",
+        alloc_header(results)
+    );
+    for result in results {
+        let answer = match &result.outcome {
+            Ok(answer) => answer_label(answer),
+            Err(e) => format!("FAILED: {}", e),
+        };
+        let (expected, check) = match (&result.check, &result.outcome) {
+            (Check::Pass, _) => (answer.clone(), "pass"),
+            (Check::Fail(expected), Ok(_)) => (expected.clone(), "FAIL"),
+            (Check::Fail(expected), Err(_)) => (expected.clone(), "-"),
+            (Check::Unknown, _) => (String::new(), "unknown"),
+        };
+        println!(
+            "{:<11} {:>4} {:>7}  {:<20} {:<20} {:<7} {:>12}{}",
+            format!("{}/day{}", result.year, result.day),
+            result.part,
+            result.example.unwrap_or_default(),
+            answer,
+            expected,
+            check,
+            format!("{:?}", result.elapsed),
+            alloc_columns(&result.allocs)
+        );
+    }
+    let failed = results.iter().filter(|r| r.failed()).count();
+    println!("{} examples, {} failed", results.len(), failed);
+}
+
+fn stat_columns(stats: &Option<Stats>) -> String {
+    match stats {
+        Some(s) => format!(
+            "{:>12} {:>12} {:>12} {:>12}",
+            format!("{:?}", s.min),
+            format!("{:?}", s.median),
+            format!("{:?}", s.mean),
+            format!("{:?}", s.stddev)
+        ),
+        None => format!("{:>12} {:>12} {:>12} {:>12}", "-", "-", "-", "-"),
+    }
+}
+
+fn median_column(stats: &Option<Stats>) -> String {
+    match stats {
+        Some(s) => format!("{:?}", s.median),
+        None => "-".to_string(),
+    }
+}
+
+pub fn print_bench(results: &[BenchResult], options: &BenchOptions) {
+    println!(
+        "{} warm-up, {} measured runs per program",
+        options.warmup, options.runs
+    );
+    print!(
+        "{:<11} {:>4}  {:>12} {:>12} {:>12} {:>12}",
+        "day", "part", "min", "median", "mean", "stddev"
+    );
+    if options.split {
+        print!(" {:>12} {:>12}", "parse", "solve");
+    }
+    println!();
+
+    for result in results {
+        print!(
+            "{:<11} {:>4}  {}",
+            format!("{}/day{}", result.year, result.day),
+            result.part,
+            stat_columns(&result.total)
+        );
+        if options.split {
+            print!(
+                " {:>12} {:>12}",
+                median_column(&result.parse),
+                median_column(&result.solve)
+            );
+        }
+        match &result.outcome {
+            Err(e) => println!("  FAILED: {}", e),
+            Ok(_) => println!(),
+        }
+    }
+}
+
+pub fn print_compare(
+    comparisons: &[Comparison],
+    baseline: &Record,
+    current: &Record,
+    threshold: f64,
+) {
+    println!(
+        "baseline run {} ({}) vs current run {} ({}), threshold {}%",
+        baseline.run, baseline.commit, current.run, current.commit, threshold
+    );
+    println!(
+        "{:<14} {:>14} {:>14} {:>9}",
+        "name", "baseline", "current", "change"
+    );
+    for comparison in comparisons {
+        println!(
+            "{:<14} {:>14} {:>14} {:>+8.1}%{}",
+            comparison.name,
+            format!("{:?}", Duration::from_nanos(comparison.baseline_ns as u64)),
+            format!("{:?}", Duration::from_nanos(comparison.current_ns as u64)),
+            comparison.change,
+            if comparison.regressed {
+                "  REGRESSED"
+            } else {
+                ""
+            }
+        );
+    }
+    let regressed = comparisons.iter().filter(|c| c.regressed).count();
+    println!("{} of {} regressed", regressed, comparisons.len());
+}
+
+/// JSON Lines for run results: status is pass, fail, unknown or error
+pub fn print_json_runs(results: &[RunResult]) {
+    for result in results {
+        let (answer, status, error) = match (&result.outcome, &result.check) {
+            (Err(e), _) => (None, "error", Some(e.clone())),
+            (Ok(answer), Check::Pass) => (Some(answer), "pass", None),
+            (Ok(answer), Check::Fail(expected)) => {
+                (Some(answer), "fail", Some(format!("expected {}", expected)))
+            }
+            (Ok(answer), Check::Unknown) => (Some(answer), "unknown", None),
+        };
+        let object = Object::new()
+            .field("name", &result.name[..])
+            .field("year", result.year)
+            .field("day", result.day)
+            .field("part", result.part)
+            .field("answer", answer)
+            .field("status", status)
+            .field("error", error)
+            .field("time_ns", result.elapsed.as_nanos());
+        let object = match result.example {
+            Some(example) => object.field("example", example),
+            None => object,
+        };
+        let object = match &result.allocs {
+            Some(a) => object
+                .field("allocs", a.allocs)
+                .field("alloc_bytes", a.bytes)
+                .field("peak_bytes", a.peak_bytes),
+            None => object,
+        };
+        println!("{}", object.render());
+    }
+}
+
+/// JSON Lines for bench results: status is ok or error, timings are null
+/// when the program failed or wasn't split
+pub fn print_json_bench(results: &[BenchResult]) {
+    for result in results {
+        let stat = |stats: &Option<Stats>, f: fn(&Stats) -> Duration| {
+            stats.as_ref().map(|s| f(s).as_nanos())
+        };
+        let object = Object::new()
+            .field("name", &result.name[..])
+            .field("year", result.year)
+            .field("day", result.day)
+            .field("part", result.part)
+            .field("answer", result.outcome.as_ref().ok())
+            .field("status", if result.failed() { "error" } else { "ok" })
+            .field("error", result.outcome.as_ref().err().cloned())
+            .field("runs", result.runs)
+            .field("min_ns", stat(&result.total, |s| s.min))
+            .field("median_ns", stat(&result.total, |s| s.median))
+            .field("mean_ns", stat(&result.total, |s| s.mean))
+            .field("stddev_ns", stat(&result.total, |s| s.stddev))
+            .field("parse_median_ns", stat(&result.parse, |s| s.median))
+            .field("solve_median_ns", stat(&result.solve, |s| s.median));
+        println!("{}", object.render());
+    }
+}
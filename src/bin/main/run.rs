@@ -0,0 +1,125 @@
+use crate::alloc::{self, AllocStats};
+use crate::answers::{Answers, Check};
+use days::solution::{input_set_path, Answer, Entry, Program};
+
+use std::fs;
+use std::io::{self, Read};
+use std::panic;
+use std::time::{Duration, Instant};
+
+/// Outcome of running one day/part against its input
+pub struct RunResult {
+    pub name: String,
+    pub year: u32,
+    pub day: u32,
+    pub part: usize,
+    /// Number of the day's example that was the input, counting from 1
+    pub example: Option<usize>,
+    pub outcome: Result<Answer, String>,
+    pub check: Check,
+    pub elapsed: Duration,
+    /// Allocations of the solver, when accounting is enabled
+    pub allocs: Option<AllocStats>,
+}
+impl RunResult {
+    pub fn failed(&self) -> bool {
+        self.outcome.is_err() || matches!(self.check, Check::Fail(_))
+    }
+
+    pub fn check_against(&mut self, answers: &Answers) {
+        if let Ok(answer) = &self.outcome {
+            self.check = answers.check(&self.name, answer);
+        }
+    }
+}
+
+/// Where a program's puzzle input comes from
+pub enum InputSource {
+    /// `inputs/<set>/<program input>`, e.g. `inputs/example/2021/day20.txt`
+    Set(String),
+    /// An explicit file, used as is for every program
+    File(String),
+    /// Input already read from stdin
+    Text(String),
+}
+impl InputSource {
+    /// Read stdin up front so the same text can be fed to repeated runs
+    pub fn stdin() -> Result<InputSource, String> {
+        let mut text = String::new();
+        io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|e| format!("can't read stdin: {}", e))?;
+        Ok(InputSource::Text(text))
+    }
+}
+
+/// Read the input of a day, whose puzzle input is `file` within an input set
+pub fn read_input(file: &str, source: &InputSource) -> Result<String, String> {
+    let path = match source {
+        InputSource::Set(set) => input_set_path(set, file),
+        InputSource::File(path) => path.into(),
+        InputSource::Text(text) => return Ok(text.clone()),
+    };
+    fs::read_to_string(&path).map_err(|e| format!("can't read {}: {}", path.display(), e))
+}
+
+/// Time a single call of `entry`, turning a solver error or a panic into an error message
+pub fn timed_call(entry: Entry, input: &str) -> (Result<Answer, String>, Duration) {
+    let start = Instant::now();
+    let answer = panic::catch_unwind(|| entry(input));
+    let elapsed = start.elapsed();
+    let outcome = match answer {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Err("panicked".to_string()),
+    };
+    (outcome, elapsed)
+}
+
+pub fn run_program(program: &Program, source: &InputSource) -> RunResult {
+    let ((outcome, elapsed), allocs) = match read_input(&program.input, source) {
+        Ok(input) => alloc::measure(|| timed_call(program.entry, &input)),
+        Err(e) => ((Err(e), Duration::ZERO), None),
+    };
+    RunResult {
+        name: program.name.clone(),
+        year: program.year,
+        day: program.day,
+        part: program.part,
+        example: None,
+        outcome,
+        check: Check::Unknown,
+        elapsed,
+        allocs,
+    }
+}
+
+/// Run a program on each of its day's examples that gives an answer for its
+/// part, checked against that answer
+pub fn run_examples(program: &Program) -> Vec<RunResult> {
+    program
+        .examples
+        .iter()
+        .enumerate()
+        .filter_map(|(i, example)| {
+            let expected = example.answer(program.part)?;
+            let ((outcome, elapsed), allocs) =
+                alloc::measure(|| timed_call(program.entry, example.input));
+            let check = match &outcome {
+                Ok(answer) => Check::of(Some(expected), answer),
+                Err(_) => Check::Fail(expected.to_string()),
+            };
+            Some(RunResult {
+                name: program.name.clone(),
+                year: program.year,
+                day: program.day,
+                part: program.part,
+                example: Some(i + 1),
+                outcome,
+                check,
+                elapsed,
+                allocs,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,414 @@
+// `new-day [YEAR] N`: generate the skeleton of a day in the layout the other
+// days use, register it in its year's module behind its own cargo feature and
+// create its empty input files. The year module itself has to exist already.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn module_source(year: u32, day: u32) -> String {
+    r#"// AoC YYYY Day N
+
+const DAY: u32 = N;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[Example {
+    input: include_str!("../../../../inputs/example/YYYY/dayN.txt"),
+    answers: &[None, None],
+}];
+
+mod puzzle {
+    use super::DAY;
+    use crate::util::error::AocError;
+
+    pub struct Input {
+        pub lines: Vec<String>,
+    }
+
+    pub mod parser {
+        use super::*;
+
+        pub fn parse_input(input: &str) -> Result<Input, AocError> {
+            let lines: Vec<String> = input
+                .lines()
+                .map(|l| l.trim())
+                .filter(|l| !l.is_empty())
+                .map(String::from)
+                .collect();
+            if lines.is_empty() {
+                return Err(AocError::new(DAY, "empty input"));
+            }
+            Ok(Input { lines })
+        }
+    }
+}
+
+use crate::solution::{Answer, Error, Result};
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let input = puzzle::parser::parse_input(input)?;
+    Err(Error::no_answer(&format!(
+        "{} lines read, part one isn't solved yet",
+        input.lines.len()
+    )))
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let input = puzzle::parser::parse_input(input)?;
+    Err(Error::no_answer(&format!(
+        "{} lines read, part two isn't solved yet",
+        input.lines.len()
+    )))
+}
+
+pub struct DayN;
+impl crate::solution::Solution for DayN {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![part_one, part_two]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| {
+            puzzle::parser::parse_input(input)
+                .map(drop)
+                .map_err(Error::from)
+        })
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "needs the example and its answer"]
+    fn test_part_one_example() {
+        assert_eq!(part_one(EXAMPLES[0].input), Ok(Answer::Integer(0)));
+    }
+
+    #[test]
+    #[ignore = "needs the example and its answer"]
+    fn test_part_two_example() {
+        assert_eq!(part_two(EXAMPLES[0].input), Ok(Answer::Integer(0)));
+    }
+}
+"#
+    .replace("YYYY", &year.to_string())
+    .replace("DayN", &format!("Day{}", day))
+    .replace("dayN", &format!("day{}", day))
+    .replace("Day N", &format!("Day {}", day))
+    .replace("= N;", &format!("= {};", day))
+}
+
+/// Insert `block` among the lines that `day_of` recognizes, keeping them
+/// ordered by day and indented like their neighbours
+fn insert_ordered<F>(source: &str, day: u32, block: &[String], day_of: F) -> Result<String, String>
+where
+    F: Fn(&str) -> Option<u32>,
+{
+    let lines: Vec<&str> = source.lines().collect();
+    let days: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, day_of(l.trim())?)))
+        .collect();
+    if days.iter().any(|(_, d)| *d == day) {
+        return Err(format!("day{} is already registered", day));
+    }
+    let (last, _) = *days.last().ok_or("no registered days to insert next to")?;
+    // entries start at the recognized line and are shaped like `block`
+    let at = days
+        .iter()
+        .find(|(_, d)| *d > day)
+        .map_or(last + block.len(), |(i, _)| *i);
+    let neighbour = lines[days
+        .iter()
+        .map(|(i, _)| *i)
+        .find(|i| *i >= at)
+        .unwrap_or(last)];
+    let indent = &neighbour[..neighbour.len() - neighbour.trim_start().len()];
+
+    let mut output: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    output.splice(at..at, block.iter().map(|l| format!("{}{}", indent, l)));
+    Ok(output.join("\n") + "\n")
+}
+
+/// Name of the cargo feature for a day, e.g. `y2021-day11`
+fn feature(year: u32, day: u32) -> String {
+    format!("y{}-day{}", year, day)
+}
+
+/// Recognizes the `#[cfg(feature = "yYYYY-dayN")]` lines that start each
+/// day's entries in its year module
+fn feature_gate_day(year: u32) -> impl Fn(&str) -> Option<u32> {
+    let prefix = format!("#[cfg(feature = \"y{}-day", year);
+    move |line| {
+        line.strip_prefix(&prefix)?
+            .strip_suffix("\")]")?
+            .parse()
+            .ok()
+    }
+}
+
+fn feature_gated(year: u32, day: u32, item: String) -> Vec<String> {
+    vec![
+        format!("#[cfg(feature = \"{}\")]", feature(year, day)),
+        item,
+    ]
+}
+
+/// The year module's declarations and its `solutions`, which gate each day
+/// on the same feature and so have to be edited apart
+fn split_year_module(year_rs: &str) -> Result<(&str, &str), String> {
+    let at = year_rs
+        .find("pub fn solutions")
+        .ok_or("no solutions() in the year module")?;
+    Ok(year_rs.split_at(at))
+}
+
+/// Add `pub mod dayN;` to the year module
+fn register_module(year_rs: &str, year: u32, day: u32) -> Result<String, String> {
+    let (declarations, solutions) = split_year_module(year_rs)?;
+    let block = feature_gated(year, day, format!("pub mod day{};", day));
+    Ok(insert_ordered(declarations, day, &block, feature_gate_day(year))? + solutions)
+}
+
+/// Add `&dayN::DayN,` to the year module's `solutions`
+fn register_solution(year_rs: &str, year: u32, day: u32) -> Result<String, String> {
+    let (declarations, solutions) = split_year_module(year_rs)?;
+    let block = feature_gated(year, day, format!("&day{0}::Day{0},", day));
+    Ok(declarations.to_string() + &insert_ordered(solutions, day, &block, feature_gate_day(year))?)
+}
+
+/// Add the day's feature and include it in its year's feature
+fn register_feature(cargo_toml: &str, year: u32, day: u32) -> Result<String, String> {
+    let prefix = format!("y{}-day", year);
+    let with_feature = insert_ordered(
+        cargo_toml,
+        day,
+        &[format!("{} = []", feature(year, day))],
+        |l| l.strip_prefix(&prefix)?.split_once(" = ")?.0.parse().ok(),
+    )?;
+    insert_ordered(
+        &with_feature,
+        day,
+        &[format!("\"{}\",", feature(year, day))],
+        |l| {
+            l.strip_prefix('"')?
+                .strip_prefix(&prefix)?
+                .strip_suffix("\",")?
+                .parse()
+                .ok()
+        },
+    )
+}
+
+/// The most recent year with a module under `days_dir`
+fn latest_year(days_dir: &Path) -> Result<u32, String> {
+    let entries =
+        fs::read_dir(days_dir).map_err(|e| format!("can't read {}: {}", days_dir.display(), e))?;
+    entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name();
+            name.to_str()?.strip_prefix('y')?.parse().ok()
+        })
+        .max()
+        .ok_or_else(|| format!("no year modules in {}", days_dir.display()))
+}
+
+/// Create day `day` of `year`, by default the latest year, under the
+/// project `root`, returning the files written
+pub fn new_day(root: &Path, year: Option<u32>, day: u32) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("no day {} in the advent calendar", day));
+    }
+    let days_dir = root.join("src/lib/days");
+    let year = match year {
+        Some(year) => year,
+        None => latest_year(&days_dir)?,
+    };
+    let year_dir = days_dir.join(format!("y{}", year));
+    if !year_dir.is_dir() {
+        return Err(format!(
+            "no module for {0} yet, add src/lib/days/y{0}/mod.rs and its features like y2021's",
+            year
+        ));
+    }
+    let module = year_dir.join(format!("day{}.rs", day));
+    let inputs: Vec<PathBuf> = ["real", "example"]
+        .iter()
+        .map(|set| {
+            root.join("inputs")
+                .join(set)
+                .join(year.to_string())
+                .join(format!("day{}.txt", day))
+        })
+        .collect();
+    if let Some(existing) = std::iter::once(&module).chain(&inputs).find(|p| p.exists()) {
+        return Err(format!(
+            "{}/day{} already exists, not overwriting {}",
+            year,
+            day,
+            existing.display()
+        ));
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|e| format!("can't read {}: {}", path.display(), e))
+    };
+    let year_rs = year_dir.join("mod.rs");
+    let cargo_toml = root.join("Cargo.toml");
+    let registered_year = register_module(&read(&year_rs)?, year, day)
+        .and_then(|source| register_solution(&source, year, day))?;
+    let registered_feature = register_feature(&read(&cargo_toml)?, year, day)?;
+
+    let write = |path: &Path, contents: &str| {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("can't create {}: {}", dir.display(), e))?;
+        }
+        fs::write(path, contents).map_err(|e| format!("can't write {}: {}", path.display(), e))
+    };
+    write(&module, &module_source(year, day))?;
+    for input in &inputs {
+        write(input, "")?;
+    }
+    write(&year_rs, &registered_year)?;
+    write(&cargo_toml, &registered_feature)?;
+
+    let mut written = vec![module];
+    written.extend(inputs);
+    written.extend([year_rs, cargo_toml]);
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR_RS: &str = r#"pub const YEAR: u32 = 2021;
+
+#[cfg(feature = "y2021-day11")]
+pub mod day11;
+#[cfg(feature = "y2021-day13")]
+pub mod day13;
+
+pub fn solutions() -> Vec<&'static dyn Solution> {
+    let solutions: &[&'static dyn Solution] = &[
+        #[cfg(feature = "y2021-day11")]
+        &day11::Day11,
+        #[cfg(feature = "y2021-day13")]
+        &day13::Day13,
+    ];
+    solutions.to_vec()
+}
+"#;
+
+    #[test]
+    fn test_register_module() {
+        assert_eq!(
+            register_module(YEAR_RS, 2021, 12).unwrap(),
+            YEAR_RS.replace(
+                "pub mod day11;\n",
+                "pub mod day11;\n#[cfg(feature = \"y2021-day12\")]\npub mod day12;\n"
+            )
+        );
+        assert_eq!(
+            register_module(YEAR_RS, 2021, 14).unwrap().lines().nth(6),
+            Some(r#"#[cfg(feature = "y2021-day14")]"#)
+        );
+        assert_eq!(
+            register_module(YEAR_RS, 2021, 1).unwrap().lines().nth(3),
+            Some("pub mod day1;")
+        );
+        assert!(register_module(YEAR_RS, 2021, 13).is_err());
+        assert!(register_module(YEAR_RS, 2022, 1).is_err());
+        assert!(register_module("pub mod day11;\n", 2021, 1).is_err());
+    }
+
+    #[test]
+    fn test_register_solution() {
+        assert_eq!(
+            register_solution(YEAR_RS, 2021, 12).unwrap(),
+            YEAR_RS.replace(
+                "&day11::Day11,\n",
+                "&day11::Day11,\n        #[cfg(feature = \"y2021-day12\")]\n        &day12::Day12,\n"
+            )
+        );
+        assert!(register_solution(YEAR_RS, 2021, 13).is_err());
+
+        // declaring a day doesn't count as listing it, nor the other way round
+        let both = register_module(YEAR_RS, 2021, 1)
+            .and_then(|source| register_solution(&source, 2021, 1))
+            .unwrap();
+        assert!(both.contains("pub mod day1;\n"));
+        assert!(both.contains("        &day1::Day1,\n"));
+    }
+
+    #[test]
+    fn test_register_feature() {
+        let cargo_toml = r#"[features]
+default = ["all"]
+all = [
+    "y2020",
+    "y2021",
+]
+y2020 = [
+    "y2020-day12",
+]
+y2021 = [
+    "y2021-day11",
+    "y2021-day13",
+]
+y2020-day12 = []
+y2021-day11 = []
+y2021-day13 = ["dep:nalgebra"]
+"#;
+        assert_eq!(
+            register_feature(cargo_toml, 2021, 12).unwrap(),
+            r#"[features]
+default = ["all"]
+all = [
+    "y2020",
+    "y2021",
+]
+y2020 = [
+    "y2020-day12",
+]
+y2021 = [
+    "y2021-day11",
+    "y2021-day12",
+    "y2021-day13",
+]
+y2020-day12 = []
+y2021-day11 = []
+y2021-day12 = []
+y2021-day13 = ["dep:nalgebra"]
+"#
+        );
+        assert!(register_feature(cargo_toml, 2021, 11).is_err());
+        assert!(register_feature(cargo_toml, 2020, 13).is_ok());
+    }
+
+    #[test]
+    fn test_module_source() {
+        let source = module_source(2022, 7);
+        assert!(source.starts_with("// AoC 2022 Day 7\n\nconst DAY: u32 = 7;\n"));
+        assert!(source.contains("include_str!(\"../../../../inputs/example/2022/day7.txt\")"));
+        assert!(source.contains("impl crate::solution::Solution for Day7 {"));
+        assert!(!source.contains("DayN") && !source.contains("dayN") && !source.contains("YYYY"));
+    }
+}
@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+impl Stats {
+    /// Summarize a set of samples, None if there are none.
+    /// stddev is the sample standard deviation, zero for a single sample.
+    pub fn of(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n % 2 == 0 {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let variance = if n > 1 {
+            let mean_nanos = mean.as_nanos() as f64;
+            sorted
+                .iter()
+                .map(|d| (d.as_nanos() as f64 - mean_nanos).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(Stats::of(&[]), None);
+
+        let single = Stats::of(&[ms(5)]).unwrap();
+        assert_eq!(single.min, ms(5));
+        assert_eq!(single.median, ms(5));
+        assert_eq!(single.stddev, Duration::ZERO);
+
+        let odd = Stats::of(&[ms(9), ms(1), ms(5)]).unwrap();
+        assert_eq!(odd.min, ms(1));
+        assert_eq!(odd.median, ms(5));
+        assert_eq!(odd.mean, ms(5));
+        assert_eq!(odd.stddev, ms(4));
+
+        let even = Stats::of(&[ms(4), ms(1), ms(2), ms(3)]).unwrap();
+        assert_eq!(even.median, Duration::from_micros(2500));
+    }
+}
@@ -1,19 +1,108 @@
-#![feature(hash_drain_filter)]
-#![feature(binary_heap_retain)]
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+//! Advent of Code solutions as a library. [`days`] lists what's compiled in
+//! and [`solve`] runs one part on a puzzle input:
+//!
+//! ```text
+//! let answer = days::solve(2021, 15, 2, &input)?;
+//! ```
+//!
+//! The year and day modules, e.g. `y2021::day15`, are public for tools poking
+//! at one puzzle's internals, but their layout differs from day to day and
+//! isn't part of this API.
 
+pub mod session;
+pub mod solution;
 pub mod util;
+pub mod y2021;
+
+pub use solution::{Answer, Error, Example, Result};
+
+use solution::{registry, Solution};
+
+/// A day compiled into the crate
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    /// Number of parts solved, counting from part 1
+    pub parts: usize,
+    /// Examples from the puzzle description
+    pub examples: &'static [Example],
+}
+impl Day {
+    fn of(solution: &dyn Solution) -> Day {
+        Day {
+            year: solution.year(),
+            day: solution.day(),
+            parts: solution.parts().len(),
+            examples: solution.examples(),
+        }
+    }
+}
+
+/// Every day compiled in, ordered by year then day
+pub fn days() -> Vec<Day> {
+    registry::solutions().into_iter().map(Day::of).collect()
+}
+
+/// Solve `part` (1 or 2) of `day` in `year` for the puzzle `input`
+pub fn solve(year: u32, day: u32, part: usize, input: &str) -> Result<Answer> {
+    let solution = registry::solutions()
+        .into_iter()
+        .find(|s| (s.year(), s.day()) == (year, day))
+        .ok_or(Error::UnknownDay { year, day })?;
+    let entry = part
+        .checked_sub(1)
+        .and_then(|i| solution.parts().get(i).copied())
+        .ok_or(Error::UnknownPart { year, day, part })?;
+    entry(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days() {
+        let days = days();
+        assert!(days
+            .windows(2)
+            .all(|w| (w[0].year, w[0].day) < (w[1].year, w[1].day)));
+        assert!(days.iter().all(|d| d.parts > 0));
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            solve(2021, 26, 1, ""),
+            Err(Error::UnknownDay {
+                year: 2021,
+                day: 26
+            })
+        );
+        if let Some(day) = days().first() {
+            let (year, day, parts) = (day.year, day.day, day.parts);
+            for part in [0, parts + 1] {
+                assert_eq!(
+                    solve(year, day, part, ""),
+                    Err(Error::UnknownPart { year, day, part })
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "y2021-day11")]
+    fn test_solve_example() {
+        let input = y2021::day11::EXAMPLES[0].input;
+        assert_eq!(solve(2021, 11, 1, input), Ok(Answer::Integer(1656)));
+        assert_eq!(solve(2021, 11, 2, input), Ok(Answer::Integer(195)));
+        assert_eq!(
+            solve(2020, 11, 1, input),
+            Err(Error::UnknownDay {
+                year: 2020,
+                day: 11
+            })
+        );
+        assert!(matches!(solve(2021, 11, 1, "12\nx3"), Err(Error::Parse(_))));
+    }
+}
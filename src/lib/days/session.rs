@@ -0,0 +1,57 @@
+// Interactive sessions for poking at a day's puzzle state while debugging. A
+// day opts in by returning a `Start` from `Solution::session`, built from its
+// parsed input; the runner's `repl` command then feeds the session one
+// command per line and prints what comes back. The commands live next to the
+// state they drive, in the day's own module.
+
+use crate::solution::Result;
+
+/// A command a session understands, as listed by `help`
+pub struct Command {
+    pub name: &'static str,
+    /// Arguments as shown in help, e.g. `[N]`
+    pub args: &'static str,
+    pub help: &'static str,
+}
+
+pub trait Session {
+    fn commands(&self) -> &'static [Command];
+
+    /// Carry out one of `commands`, returning what to print
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String>;
+
+    /// The current state
+    fn show(&self) -> String;
+}
+
+/// Builds a session from the raw puzzle input
+pub type Start = fn(&str) -> Result<Box<dyn Session>>;
+
+/// The optional repeat count taken by stepping commands, 1 when left out
+pub fn count(args: &[&str]) -> Result<usize, String> {
+    match args {
+        [] => Ok(1),
+        [n] => n
+            .parse()
+            .map_err(|_| format!("expected a count, got {}", n)),
+        _ => Err(format!("expected at most a count, got {}", args.join(" "))),
+    }
+}
+
+/// Error for a command that isn't in `Session::commands`
+pub fn unknown(command: &str) -> String {
+    format!("unknown command {}, try help", command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count() {
+        assert_eq!(count(&[]), Ok(1));
+        assert_eq!(count(&["12"]), Ok(12));
+        assert!(count(&["-1"]).is_err());
+        assert!(count(&["1", "2"]).is_err());
+    }
+}
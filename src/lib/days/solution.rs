@@ -0,0 +1,291 @@
+// Each year has a module, e.g. `y2021`, with a dayNN module per solved day.
+// Every dayNN module exposes a unit struct implementing Solution, each year
+// module lists its own, and the registry below collects the years. Runners,
+// benches and tests should go through here rather than naming day modules
+// directly.
+
+use crate::session;
+use crate::util::error::AocError;
+
+use std::fmt;
+use std::path::PathBuf;
+
+/// Puzzle inputs live in `inputs/<set>/<year>/`, one directory per input set,
+/// e.g. `inputs/real/2021/day15.txt` for our own puzzle input.
+pub const INPUTS_DIR: &str = "inputs";
+pub const DEFAULT_INPUT_SET: &str = "real";
+
+pub fn input_set_path(set: &str, file: &str) -> PathBuf {
+    [INPUTS_DIR, set, file].iter().collect()
+}
+
+/// A puzzle answer. Most are integers, but some puzzles want text, e.g. the
+/// letters drawn by day 13 part two.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(u64),
+    Signed(i64),
+    Text(String),
+    /// A drawing, one string per line, for output that isn't recognized as text
+    Lines(Vec<String>),
+}
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+impl From<u64> for Answer {
+    fn from(n: u64) -> Answer {
+        Answer::Integer(n)
+    }
+}
+impl From<i64> for Answer {
+    fn from(n: i64) -> Answer {
+        Answer::Signed(n)
+    }
+}
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+/// Why a part produced no answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The puzzle input couldn't be parsed
+    Parse(AocError),
+    /// The input was understood, but the solver found no answer
+    NoAnswer(String),
+    /// No solution for the day is compiled in
+    UnknownDay { year: u32, day: u32 },
+    /// The day has a solution, but not for this part
+    UnknownPart { year: u32, day: u32, part: usize },
+}
+impl Error {
+    pub fn no_answer(why: &str) -> Error {
+        Error::NoAnswer(why.to_string())
+    }
+}
+impl From<AocError> for Error {
+    fn from(e: AocError) -> Error {
+        Error::Parse(e)
+    }
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::NoAnswer(msg) => write!(f, "no answer: {}", msg),
+            Error::UnknownDay { year, day } => write!(f, "no solution for {} day {}", year, day),
+            Error::UnknownPart { year, day, part } => {
+                write!(f, "{} day {} has no part {}", year, day, part)
+            }
+        }
+    }
+}
+impl std::error::Error for Error {}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Entry point for one part of a puzzle, taking the raw puzzle input
+pub type Entry = fn(&str) -> Result<Answer>;
+
+/// Parses the raw puzzle input and throws the result away. Lets runners time
+/// parsing apart from solving.
+pub type Parse = fn(&str) -> Result<()>;
+
+/// An example from the puzzle description, shared by a day's tests and the
+/// runner's `--example` mode
+pub struct Example {
+    pub input: &'static str,
+    /// Expected answer of each part as printed, `None` where the puzzle
+    /// doesn't give one for this input
+    pub answers: &'static [Option<&'static str>],
+}
+impl Example {
+    /// Expected answer for `part`, counting from 1
+    pub fn answer(&self, part: usize) -> Option<&'static str> {
+        part.checked_sub(1)
+            .and_then(|i| self.answers.get(i))
+            .copied()
+            .flatten()
+    }
+}
+
+pub trait Solution: Sync {
+    /// Year of the event, e.g. 2021
+    fn year(&self) -> u32;
+
+    /// Day of the advent calendar, 1-25
+    fn day(&self) -> u32;
+
+    /// Entry points in order, i.e. `parts()[0]` is part one
+    fn parts(&self) -> Vec<Entry>;
+
+    /// Parser shared by the parts, if the day has one separate from solving
+    fn parse(&self) -> Option<Parse> {
+        None
+    }
+
+    /// e.g. `2021/day15`
+    fn name(&self) -> String {
+        format!("{}/day{}", self.year(), self.day())
+    }
+
+    /// Puzzle input path within an input set, see `input_set_path`
+    fn input_path(&self) -> String {
+        format!("{}/day{}.txt", self.year(), self.day())
+    }
+
+    /// Examples from the puzzle description
+    fn examples(&self) -> &'static [Example] {
+        &[]
+    }
+
+    /// Interactive session on the parsed input, for days that offer one
+    fn session(&self) -> Option<session::Start> {
+        None
+    }
+}
+
+/// A single runnable day/part, e.g. `2021/day15-2`
+pub struct Program {
+    pub name: String,
+    pub year: u32,
+    pub day: u32,
+    pub part: usize,
+    pub input: String,
+    pub entry: Entry,
+    pub parse: Option<Parse>,
+    pub examples: &'static [Example],
+}
+
+pub mod registry {
+    use super::{Program, Solution};
+    use crate::y2021;
+
+    /// The solutions of each year, oldest first
+    const YEARS: &[fn() -> Vec<&'static dyn Solution>] = &[y2021::solutions];
+
+    /// All solutions compiled in, ordered by year then day
+    pub fn solutions() -> Vec<&'static dyn Solution> {
+        YEARS.iter().flat_map(|solutions| solutions()).collect()
+    }
+
+    pub fn programs_of(solution: &dyn Solution) -> Vec<Program> {
+        solution
+            .parts()
+            .into_iter()
+            .enumerate()
+            .map(|(i, entry)| Program {
+                name: format!("{}-{}", solution.name(), i + 1),
+                year: solution.year(),
+                day: solution.day(),
+                part: i + 1,
+                input: solution.input_path(),
+                entry,
+                parse: solution.parse(),
+                examples: solution.examples(),
+            })
+            .collect()
+    }
+
+    /// Every day/part, ordered by year, day then part
+    pub fn programs() -> Vec<Program> {
+        solutions().into_iter().flat_map(programs_of).collect()
+    }
+
+    /// The item called `name`, or when `name` leaves out the year the only
+    /// one of that name in any year
+    fn by_name<T>(items: Vec<T>, name: &str, name_of: impl Fn(&T) -> String) -> Option<T> {
+        let mut items = items.into_iter();
+        if name.contains('/') {
+            return items.find(|item| name_of(item) == name);
+        }
+        let suffix = format!("/{}", name);
+        let mut matching = items.filter(|item| name_of(item).ends_with(&suffix));
+        let item = matching.next()?;
+        matching.next().is_none().then_some(item)
+    }
+
+    /// Find a program by name, e.g. `2021/day15-2`. The year can be left out,
+    /// as in `day15-2`, when only one year has that day.
+    pub fn find(name: &str) -> Option<Program> {
+        by_name(programs(), name, |program| program.name.clone())
+    }
+
+    /// Find a day by name, e.g. `2021/day23` or `day23`, like `find`
+    pub fn find_solution(name: &str) -> Option<&'static dyn Solution> {
+        by_name(solutions(), name, |solution| solution.name())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::solution::input_set_path;
+
+        #[test]
+        fn test_programs_ordered_and_unique() {
+            let order: Vec<(u32, u32, usize)> =
+                programs().iter().map(|p| (p.year, p.day, p.part)).collect();
+            let mut sorted = order.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+            assert_eq!(order, sorted);
+
+            let names: Vec<String> = programs().into_iter().map(|p| p.name).collect();
+            for (day, name) in [
+                (11, "2021/day11-1"),
+                (24, "2021/day24-2"),
+                (25, "2021/day25-1"),
+            ] {
+                let compiled_in = solutions().iter().any(|s| s.day() == day);
+                assert_eq!(names.contains(&name.to_string()), compiled_in);
+            }
+        }
+
+        #[test]
+        fn test_examples_answer_each_part() {
+            for solution in solutions() {
+                for example in solution.examples() {
+                    assert!(
+                        example.answers.len() <= solution.parts().len(),
+                        "{} has an example with too many answers",
+                        solution.name()
+                    );
+                }
+            }
+            if cfg!(feature = "y2021-day11") {
+                let program = find("2021/day11-2").unwrap();
+                assert_eq!(program.examples[0].answer(2), Some("195"));
+                assert_eq!(program.examples[0].answer(0), None);
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "y2021-day15")]
+        fn test_find() {
+            let program = find("2021/day15-2").unwrap();
+            assert_eq!((program.year, program.day, program.part), (2021, 15, 2));
+            assert_eq!(program.input, "2021/day15.txt");
+            assert_eq!(
+                input_set_path("example", &program.input),
+                std::path::Path::new("inputs/example/2021/day15.txt")
+            );
+            assert_eq!(find("day15-2").map(|p| p.name), Some(program.name));
+            assert!(find("2021/day15-3").is_none());
+            assert!(find("2021/day26-1").is_none());
+            assert!(find("2020/day15-1").is_none());
+
+            let solution = find_solution("day15").unwrap();
+            assert_eq!(solution.name(), "2021/day15");
+            assert!(find_solution("2021/day15-2").is_none());
+        }
+    }
+}
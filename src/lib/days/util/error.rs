@@ -0,0 +1,144 @@
+// Errors for malformed puzzle input. Parsers report where they gave up and
+// what they found there, so a bad input file gets a readable diagnostic
+// instead of `None` or a panic.
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub day: u32,
+    /// 1-based position in the input, when the error can be pinned down
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    /// The offending input
+    pub text: String,
+    /// What was expected instead
+    pub message: String,
+}
+impl AocError {
+    /// An error about the input as a whole, e.g. it being empty
+    pub fn new(day: u32, message: &str) -> AocError {
+        AocError {
+            day,
+            line: None,
+            column: None,
+            text: String::new(),
+            message: message.to_string(),
+        }
+    }
+
+    /// An error about one line, `index` counting from 0 as `str::lines` does.
+    /// Points at the first non-blank character of the line.
+    pub fn on_line(day: u32, index: usize, line: &str, message: &str) -> AocError {
+        let indent = line.chars().take_while(|c| c.is_whitespace()).count();
+        AocError::at(day, index, indent, line.trim(), message)
+    }
+
+    /// An error at a character `column` of line `index`, both counting from 0
+    pub fn at(day: u32, index: usize, column: usize, text: &str, message: &str) -> AocError {
+        AocError {
+            day,
+            line: Some(index + 1),
+            column: Some(column + 1),
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// An error at byte `offset` into `input`, showing the rest of that line
+    pub fn at_offset(day: u32, input: &str, offset: usize, message: &str) -> AocError {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let index = input[..offset].matches('\n').count();
+        let column = input[line_start..offset].chars().count();
+        let text = match input[offset..line_end].trim_end() {
+            "" if offset == input.len() => "end of input",
+            text => text,
+        };
+        AocError::at(day, index, column, text, message)
+    }
+
+    /// Move an error found in part of the input down by `lines`, e.g. when
+    /// line `lines` was parsed on its own
+    pub fn offset_by_lines(mut self, lines: usize) -> AocError {
+        self.line = self.line.map(|line| line + lines);
+        self
+    }
+
+    /// Locate a nom failure within the whole `input` the parser was given
+    pub fn from_nom(
+        day: u32,
+        input: &str,
+        error: nom::Err<nom::error::Error<&str>>,
+        message: &str,
+    ) -> AocError {
+        match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                AocError::at_offset(day, input, input.len() - e.input.len(), message)
+            }
+            nom::Err::Incomplete(_) => AocError::at_offset(day, input, input.len(), message),
+        }
+    }
+}
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}", self.day)?;
+        if let Some(line) = self.line {
+            write!(f, ", line {}", line)?;
+        }
+        if let Some(column) = self.column {
+            write!(f, ", column {}", column)?;
+        }
+        write!(f, ": {}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ", found {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_on_line() {
+        let e = AocError::on_line(12, 2, "  start-", "expected `cave-cave`");
+        assert_eq!((e.line, e.column), (Some(3), Some(3)));
+        assert_eq!(
+            e.to_string(),
+            r#"day 12, line 3, column 3: expected `cave-cave`, found "start-""#
+        );
+        assert_eq!(
+            AocError::new(11, "empty input").to_string(),
+            "day 11: empty input"
+        );
+    }
+
+    #[test]
+    fn test_at_offset() {
+        let input = "on x=1..2\noff x=1..y\n";
+        let e = AocError::at_offset(22, input, input.find('y').unwrap(), "expected a number");
+        assert_eq!((e.line, e.column), (Some(2), Some(10)));
+        assert_eq!(e.text, "y");
+
+        let e = AocError::at_offset(22, input, input.len(), "expected a reboot step");
+        assert_eq!((e.line, e.column), (Some(3), Some(1)));
+        assert_eq!(e.text, "end of input");
+    }
+
+    #[test]
+    fn test_from_nom() {
+        use nom::character::complete::digit1;
+        let input = "12\nab";
+        let rest = &input[3..];
+        let error = digit1::<&str, nom::error::Error<&str>>(rest).unwrap_err();
+        let e = AocError::from_nom(17, input, error, "expected digits");
+        assert_eq!((e.line, e.column), (Some(2), Some(1)));
+        assert_eq!(e.text, "ab");
+    }
+}
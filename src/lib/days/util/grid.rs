@@ -1,6 +1,64 @@
+use crate::util::error::AocError;
+use crate::util::pos::{Dir, Pos};
 use itertools::Itertools;
 use std::fmt::Display;
-use std::ops::{Index, IndexMut};
+use std::ops::{Index, IndexMut, Range};
+
+/// Which cells around a position count as its neighbors, as (row, col)
+/// offsets. Neighbors are visited in the order of the offsets, which for the
+/// built-in neighborhoods is top-left to bottom-right.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Neighborhood {
+    /// The 4 orthogonal cells
+    VonNeumann,
+    /// The 8 surrounding cells
+    Moore,
+    /// The 8 surrounding cells and the cell itself, e.g. for image kernels
+    MooreWithSelf,
+    Custom(&'static [(i32, i32)]),
+}
+impl Neighborhood {
+    pub fn offsets(&self) -> &'static [(i32, i32)] {
+        match self {
+            Neighborhood::VonNeumann => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Neighborhood::Moore => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+            Neighborhood::MooreWithSelf => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 0),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+            Neighborhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// Positions around (row, col) in the order of `neighborhood`, whether or
+/// not they're on a grid
+pub fn neighbor_offsets(
+    row: i32,
+    col: i32,
+    neighborhood: Neighborhood,
+) -> impl Iterator<Item = (i32, i32)> {
+    neighborhood
+        .offsets()
+        .iter()
+        .map(move |(dr, dc)| (row + dr, col + dc))
+}
 
 /// Row-major storage of NxM grid of T
 /// (0,0) is top-left, (n,m) is bottom-right
@@ -19,12 +77,64 @@ impl<T> Grid<T> {
         }
     }
 
+    /// A grid with each cell set to `f(row, col)`
+    pub fn from_fn(ncols: usize, nrows: usize, mut f: impl FnMut(usize, usize) -> T) -> Grid<T> {
+        let mut storage = Vec::with_capacity(ncols * nrows);
+        for row in 0..nrows {
+            for col in 0..ncols {
+                storage.push(f(row, col));
+            }
+        }
+        Grid { storage, ncols }
+    }
+
+    /// Parse a character map, one row per line, turning each character into
+    /// a cell with `parse` or an error message for it. Blank lines and the
+    /// whitespace around each row are skipped; rows must all be as long.
+    pub fn parse_with<E: Display>(
+        day: u32,
+        input: &str,
+        mut parse: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Grid<T>, AocError> {
+        let mut storage = vec![];
+        let mut ncols = None;
+        for (i, line) in input.lines().enumerate() {
+            let row = line.trim();
+            if row.is_empty() {
+                continue;
+            }
+            let indent = line.chars().take_while(|c| c.is_whitespace()).count();
+            let start = storage.len();
+            for (col, c) in row.chars().enumerate() {
+                let cell = parse(c).map_err(|e| {
+                    AocError::at(day, i, indent + col, &c.to_string(), &e.to_string())
+                })?;
+                storage.push(cell);
+            }
+            if *ncols.get_or_insert(storage.len() - start) != storage.len() - start {
+                return Err(AocError::on_line(day, i, line, "rows differ in length"));
+            }
+        }
+        match ncols {
+            Some(ncols) => Ok(Grid { storage, ncols }),
+            None => Err(AocError::new(day, "no grid in input")),
+        }
+    }
+
+    /// A grid of the same shape with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            storage: self.storage.iter().map(f).collect(),
+            ncols: self.ncols,
+        }
+    }
+
     pub fn width(&self) -> usize {
         self.ncols
     }
 
     pub fn height(&self) -> usize {
-        self.storage.len() / self.ncols
+        self.storage.len().checked_div(self.ncols).unwrap_or(0)
     }
 
     pub fn in_bounds(&self, row: usize, col: usize) -> bool {
@@ -51,71 +161,54 @@ impl<T> Grid<T> {
         }
     }
 
-    // all neighbor functions should be top-left to bottom-right
+    /// In-bounds neighbors of (row, col) in the order of `neighborhood`'s
+    /// offsets, without allocating
+    pub fn neighbors(
+        &self,
+        row: usize,
+        col: usize,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        neighborhood
+            .offsets()
+            .iter()
+            .filter_map(move |&(dr, dc)| self.checked_offset_pos(row, col, dr, dc))
+    }
+
+    /// Values of the neighbors of a possibly out of bounds (row, col), with
+    /// `outside` standing in for neighbors off the grid
+    pub fn neighbor_values<'a>(
+        &'a self,
+        row: i32,
+        col: i32,
+        neighborhood: Neighborhood,
+        outside: &'a T,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        neighbor_offsets(row, col, neighborhood)
+            .map(move |(r, c)| self.get_int(r, c).unwrap_or(outside))
+    }
+
     /// diagonal neighbors, i.e. for a non-edge we should have 8 diagonal neighbors
     #[allow(dead_code)]
     pub fn diag_neighbors(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
-        vec![
-            self.checked_offset_pos(row, col, -1, -1),
-            self.checked_offset_pos(row, col, -1, 0),
-            self.checked_offset_pos(row, col, -1, 1),
-            self.checked_offset_pos(row, col, 0, -1),
-            // no self
-            self.checked_offset_pos(row, col, 0, 1),
-            self.checked_offset_pos(row, col, 1, -1),
-            self.checked_offset_pos(row, col, 1, 0),
-            self.checked_offset_pos(row, col, 1, 1),
-        ]
-        .into_iter()
-        .flatten()
-        .collect_vec()
+        self.neighbors(row, col, Neighborhood::Moore).collect()
     }
 
     /// diagonal neighbors and self, ibid, including (row, col)
     #[allow(dead_code)]
     pub fn diag_neighbors_self(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
-        vec![
-            self.checked_offset_pos(row, col, -1, -1),
-            self.checked_offset_pos(row, col, -1, 0),
-            self.checked_offset_pos(row, col, -1, 1),
-            self.checked_offset_pos(row, col, 0, -1),
-            self.checked_offset_pos(row, col, 0, 0), // could be out of bounds!
-            self.checked_offset_pos(row, col, 0, 1),
-            self.checked_offset_pos(row, col, 1, -1),
-            self.checked_offset_pos(row, col, 1, 0),
-            self.checked_offset_pos(row, col, 1, 1),
-        ]
-        .into_iter()
-        .flatten()
-        .collect_vec()
+        self.neighbors(row, col, Neighborhood::MooreWithSelf)
+            .collect()
     }
 
     /// orthogonal neighbors, i.e. for a non-edge we should have 4 orthogonal neighbors
     #[allow(dead_code)]
     pub fn orthog_neighbors(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
-        vec![
-            self.checked_offset_pos(row, col, -1, 0),
-            self.checked_offset_pos(row, col, 0, -1),
-            self.checked_offset_pos(row, col, 0, 1),
-            self.checked_offset_pos(row, col, 1, 0),
-        ]
-        .into_iter()
-        .flatten()
-        .collect_vec()
+        self.neighbors(row, col, Neighborhood::VonNeumann).collect()
     }
 
     pub fn diag_offsets(&self, row: i32, col: i32) -> Vec<(i32, i32)> {
-        vec![
-            (row - 1, col - 1),
-            (row - 1, col),
-            (row - 1, col + 1),
-            (row, col - 1),
-            (row, col),
-            (row, col + 1),
-            (row + 1, col - 1),
-            (row + 1, col),
-            (row + 1, col + 1),
-        ]
+        neighbor_offsets(row, col, Neighborhood::MooreWithSelf).collect()
     }
 
     fn index(&self, row: usize, col: usize) -> usize {
@@ -145,18 +238,128 @@ impl<T> Grid<T> {
 
     /// Get with possibly negative integers
     pub fn get_int(&self, row: i32, col: i32) -> Option<&T> {
-        if row < 0 || col < 0 {
-            None
-        } else {
-            self.get(row as usize, col as usize)
+        self.at(Pos::new(row, col))
+    }
+
+    fn pos_index(&self, pos: Pos) -> Option<usize> {
+        let (row, col) = pos.to_usize()?;
+        // checked against the storage rather than height(), which divides
+        let idx = row.checked_mul(self.ncols)?.checked_add(col)?;
+        (col < self.ncols && idx < self.storage.len()).then_some(idx)
+    }
+
+    /// Whether `pos` is on the grid
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.pos_index(pos).is_some()
+    }
+
+    pub fn at(&self, pos: Pos) -> Option<&T> {
+        self.pos_index(pos).map(|i| &self.storage[i])
+    }
+
+    pub fn at_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.pos_index(pos).map(|i| &mut self.storage[i])
+    }
+
+    /// The cell next to `pos` towards `dir`, if it's on the grid
+    pub fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        pos.checked_step(dir).filter(|next| self.contains(*next))
+    }
+
+    /// The cell next to `pos` towards `dir`, coming back in on the opposite
+    /// edge when stepping off the grid. Panics unless `pos` is on the grid,
+    /// which also rules out an empty grid.
+    pub fn wrapped_step(&self, pos: Pos, dir: Dir) -> Pos {
+        assert!(self.contains(pos), "{} out of bounds", pos);
+        // a step moves at most one cell along each axis
+        let wrap = |n: i32, dn: i32, len: usize| match n + dn {
+            next if next < 0 => len as i32 - 1,
+            next if next as usize >= len => 0,
+            next => next,
+        };
+        let offset = dir.offset();
+        Pos::new(
+            wrap(pos.row, offset.row, self.height()),
+            wrap(pos.col, offset.col, self.width()),
+        )
+    }
+
+    /// Positions of every cell, row by row
+    pub fn positions(&self) -> Positions {
+        Positions {
+            next: Pos::ORIGIN,
+            height: self.height() as i32,
+            width: self.width() as i32,
         }
     }
 
+    /// Neighbors of `pos` on the grid, like `neighbors`
+    pub fn neighbors_of(
+        &self,
+        pos: Pos,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = Pos> + '_ {
+        neighborhood.offsets().iter().filter_map(move |&offset| {
+            pos.checked_add(offset.into())
+                .filter(|next| self.contains(*next))
+        })
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.storage.iter()
     }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.storage.iter_mut()
+    }
+}
+/// Iterator over the positions of a grid, see `Grid::positions`. Counts
+/// directly rather than nesting ranges, which optimizes far worse in hot loops.
+pub struct Positions {
+    next: Pos,
+    height: i32,
+    width: i32,
+}
+impl Iterator for Positions {
+    type Item = Pos;
+
+    fn next(&mut self) -> Option<Pos> {
+        if self.next.row >= self.height {
+            return None;
+        }
+        let pos = self.next;
+        self.next.col += 1;
+        if self.next.col == self.width {
+            self.next = Pos::new(pos.row + 1, 0);
+        }
+        Some(pos)
+    }
+}
+
+impl Grid<u8> {
+    /// Parse a map of single digits, as `parse_with`
+    pub fn parse_digits(day: u32, input: &str) -> Result<Grid<u8>, AocError> {
+        Grid::parse_with(day, input, |c| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .ok_or("expected a digit 0-9")
+        })
+    }
+}
+impl Grid<bool> {
+    /// Parse a map of `on` and `off` characters, as `parse_with`
+    pub fn parse_bools(day: u32, input: &str, on: char, off: char) -> Result<Grid<bool>, AocError> {
+        Grid::parse_with(day, input, |c| match c {
+            _ if c == on => Ok(true),
+            _ if c == off => Ok(false),
+            _ => Err(format!("expected `{}` or `{}`", on, off)),
+        })
+    }
 }
+
 impl<T: Clone> Grid<T> {
+    /// Panics if the rows differ in length; `parse_with` reports that as an
+    /// input error instead
     pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
         let ncols = rows[0].len();
         for row in &rows {
@@ -186,6 +389,102 @@ impl<T: Clone> Grid<T> {
             ncols: 0,
         }
     }
+
+    // transforms, each returning a new grid
+
+    /// Rows become columns, mirroring along the top-left to bottom-right
+    /// diagonal
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height(), self.width(), |row, col| {
+            self[(col, row)].clone()
+        })
+    }
+
+    /// A quarter turn clockwise
+    pub fn rotate_90(&self) -> Grid<T> {
+        let height = self.height();
+        Grid::from_fn(height, self.width(), |row, col| {
+            self[(height - 1 - col, row)].clone()
+        })
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        let (height, width) = (self.height(), self.width());
+        Grid::from_fn(width, height, |row, col| {
+            self[(height - 1 - row, width - 1 - col)].clone()
+        })
+    }
+
+    /// A quarter turn counterclockwise
+    pub fn rotate_270(&self) -> Grid<T> {
+        let width = self.width();
+        Grid::from_fn(self.height(), width, |row, col| {
+            self[(col, width - 1 - row)].clone()
+        })
+    }
+
+    /// Mirror left to right
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width();
+        Grid::from_fn(width, self.height(), |row, col| {
+            self[(row, width - 1 - col)].clone()
+        })
+    }
+
+    /// Mirror top to bottom
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height();
+        Grid::from_fn(self.width(), height, |row, col| {
+            self[(height - 1 - row, col)].clone()
+        })
+    }
+
+    /// The cells in `rows` and `cols`. Panics if they reach past the grid,
+    /// as slicing does.
+    pub fn crop(&self, rows: Range<usize>, cols: Range<usize>) -> Grid<T> {
+        assert!(
+            rows.start <= rows.end && rows.end <= self.height(),
+            "rows {:?} out of bounds",
+            rows
+        );
+        assert!(
+            cols.start <= cols.end && cols.end <= self.width(),
+            "cols {:?} out of bounds",
+            cols
+        );
+        Grid::from_fn(cols.len(), rows.len(), |row, col| {
+            self[(rows.start + row, cols.start + col)].clone()
+        })
+    }
+
+    /// Repeat the grid `across` times to the right and `down` times below,
+    /// each copy's cells mapped by `f(cell, tile_row, tile_col)`
+    pub fn tile(
+        &self,
+        across: usize,
+        down: usize,
+        mut f: impl FnMut(&T, usize, usize) -> T,
+    ) -> Grid<T> {
+        let (height, width) = (self.height(), self.width());
+        Grid::from_fn(width * across, height * down, |row, col| {
+            f(
+                &self[(row % height, col % width)],
+                row / height,
+                col / width,
+            )
+        })
+    }
+
+    /// Surround the grid with a border `by` cells thick of `value`
+    pub fn pad(&self, by: usize, value: T) -> Grid<T> {
+        let (height, width) = (self.height(), self.width());
+        Grid::from_fn(width + 2 * by, height + 2 * by, |row, col| {
+            match (row.checked_sub(by), col.checked_sub(by)) {
+                (Some(r), Some(c)) if r < height && c < width => self[(r, c)].clone(),
+                _ => value.clone(),
+            }
+        })
+    }
 }
 impl<T> Index<(usize, usize)> for Grid<T> {
     type Output = T;
@@ -210,13 +509,29 @@ impl<T> IndexMut<(usize, usize)> for Grid<T> {
         }
     }
 }
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.at(pos)
+            .unwrap_or_else(|| panic!("{} out of bounds", pos))
+    }
+}
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let idx = self
+            .pos_index(pos)
+            .unwrap_or_else(|| panic!("{} out of bounds", pos));
+        &mut self.storage[idx]
+    }
+}
 impl<T: Display> Display for Grid<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
-        for col in 0..self.height() {
-            for row in 0..self.width() {
+        for row in 0..self.height() {
+            for col in 0..self.width() {
                 write!(f, "{}", self[(row, col)])?;
             }
-            write!(f, "\n")?;
+            writeln!(f)?;
         }
         Ok(())
     }
@@ -225,6 +540,7 @@ impl<T: Display> Display for Grid<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::prop::{self, Gen};
 
     // take a 3x3 grid and validate that we can manipulate it
     fn test_valid_access(grid: Grid<bool>) {
@@ -335,6 +651,135 @@ mod tests {
         assert_eq!(grid.diag_neighbors_self(3, 3), vec![(2, 2)]);
     }
 
+    #[test]
+    fn test_neighborhoods() {
+        let grid: Grid<bool> = Grid::fill(3, 2, false);
+
+        assert_eq!(
+            grid.neighbors(0, 1, Neighborhood::VonNeumann).collect_vec(),
+            vec![(0, 0), (0, 2), (1, 1)]
+        );
+        assert_eq!(
+            grid.neighbors(1, 2, Neighborhood::Moore).collect_vec(),
+            vec![(0, 1), (0, 2), (1, 1)]
+        );
+        let knight = Neighborhood::Custom(&[(-2, -1), (-1, -2), (1, 2), (2, 1)]);
+        assert_eq!(grid.neighbors(0, 0, knight).collect_vec(), vec![(1, 2)]);
+        assert_eq!(grid.neighbors(1, 2, knight).collect_vec(), vec![(0, 0)]);
+        assert_eq!(grid.neighbors(1, 0, knight).count(), 0);
+        assert_eq!(
+            neighbor_offsets(5, 5, knight).collect_vec(),
+            vec![(3, 4), (4, 3), (6, 7), (7, 6)]
+        );
+    }
+
+    #[test]
+    fn test_neighbor_values() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            grid.neighbor_values(0, 0, Neighborhood::MooreWithSelf, &0)
+                .collect_vec(),
+            vec![&0, &0, &0, &0, &1, &2, &0, &4, &5]
+        );
+        assert_eq!(
+            grid.neighbor_values(-1, 3, Neighborhood::VonNeumann, &0)
+                .collect_vec(),
+            vec![&0, &0, &0, &0]
+        );
+    }
+
+    #[test]
+    fn test_display_isnt_transposed() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse_digits(9, "\n  123\n\n456  \n").unwrap();
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let grid = Grid::parse_bools(20, "#.\n.#", '#', '.').unwrap();
+        assert_eq!(grid.map(|&b| b as u8).to_string(), "10\n01\n");
+
+        let e = Grid::parse_digits(9, "123\n 4x6").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            r#"day 9, line 2, column 3: expected a digit 0-9, found "x""#
+        );
+        let e = Grid::parse_bools(20, "#.\n\n.#.", '#', '.').unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            r#"day 20, line 3, column 1: rows differ in length, found ".#.""#
+        );
+        let e = Grid::parse_digits(9, " \n\n").unwrap_err();
+        assert_eq!(e.to_string(), "day 9: no grid in input");
+    }
+
+    #[test]
+    fn test_transforms() {
+        // 123
+        // 456
+        let grid = Grid::from_fn(3, 2, |row, col| row * 3 + col + 1);
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.rotate_90().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.rotate_180().to_string(), "654\n321\n");
+        assert_eq!(grid.rotate_270().to_string(), "36\n25\n14\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "321\n654\n");
+        assert_eq!(grid.flip_vertical().to_string(), "456\n123\n");
+        assert_eq!(grid.crop(0..2, 1..3).to_string(), "23\n56\n");
+        assert_eq!(
+            grid.tile(2, 2, |&n, down, across| (n + down * 3 + across) % 10)
+                .to_string(),
+            "123234\n456567\n456567\n789890\n"
+        );
+        assert_eq!(grid.pad(1, 0).to_string(), "00000\n01230\n04560\n00000\n");
+
+        let full_turn = grid.rotate_90().rotate_90().rotate_90().rotate_90();
+        assert_eq!(full_turn.to_string(), grid.to_string());
+        assert_eq!(
+            grid.rotate_90().rotate_90().to_string(),
+            grid.rotate_180().to_string()
+        );
+        assert_eq!(
+            grid.transpose().to_string(),
+            grid.rotate_90().flip_horizontal().to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_wrapped_step_on_empty_grid() {
+        Grid::<u8>::empty().wrapped_step(Pos::ORIGIN, Dir::E);
+    }
+
+    #[test]
+    fn test_pos_access() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let corner = Pos::new(1, 2);
+        assert_eq!(grid[corner], 6);
+        assert_eq!(grid.at(Pos::new(2, 0)), None);
+        assert_eq!(grid.at(Pos::new(-1, 0)), None);
+        grid[Pos::ORIGIN] = 10;
+        *grid.at_mut(corner).unwrap() += 10;
+        assert_eq!(grid.iter().copied().collect_vec(), vec![10, 2, 3, 4, 5, 16]);
+
+        assert_eq!(grid.step(corner, Dir::N), Some(Pos::new(0, 2)));
+        assert_eq!(grid.step(corner, Dir::E), None);
+        assert_eq!(grid.step(Pos::ORIGIN, Dir::NW), None);
+        assert_eq!(grid.wrapped_step(corner, Dir::SE), Pos::ORIGIN);
+        assert_eq!(grid.wrapped_step(Pos::ORIGIN, Dir::NW), corner);
+
+        assert_eq!(
+            grid.positions().collect_vec(),
+            [(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)].map(Pos::from)
+        );
+        assert_eq!(
+            grid.neighbors_of(corner, Neighborhood::Moore).collect_vec(),
+            [(0, 1), (0, 2), (1, 1)].map(Pos::from)
+        );
+    }
+
     #[test]
     fn test_offsets() {
         let grid: Grid<bool> = Grid::from_data(
@@ -375,4 +820,73 @@ mod tests {
             ]
         );
     }
+
+    /// Rectangular rows of digits, shrinking by dropping the last row or
+    /// column and by zeroing cells
+    fn rows() -> impl Gen<Value = Vec<Vec<i64>>> {
+        prop::from_fn(
+            |rng| {
+                let (width, height) = (rng.len(1..=6), rng.len(1..=6));
+                (0..height)
+                    .map(|_| (0..width).map(|_| rng.range(0..=9)).collect())
+                    .collect()
+            },
+            |rows: &Vec<Vec<i64>>| {
+                let mut smaller = vec![];
+                if rows.len() > 1 {
+                    smaller.push(rows[..rows.len() - 1].to_vec());
+                }
+                if rows[0].len() > 1 {
+                    smaller.push(rows.iter().map(|r| r[..r.len() - 1].to_vec()).collect());
+                }
+                for (r, row) in rows.iter().enumerate() {
+                    for c in (0..row.len()).filter(|c| row[*c] != 0) {
+                        let mut zeroed = rows.clone();
+                        zeroed[r][c] = 0;
+                        smaller.push(zeroed);
+                    }
+                }
+                smaller
+            },
+        )
+    }
+
+    #[test]
+    fn test_prop_matches_nested_vecs() {
+        prop::check(&rows(), |rows| {
+            let grid = Grid::from_rows(rows.clone());
+            let (height, width) = (rows.len(), rows[0].len());
+            let naive = |r: usize, c: usize| rows.get(r).and_then(|row| row.get(c));
+            let cells_match = (0..=height).all(|r| {
+                (0..=width).all(|c| {
+                    grid.get(r, c) == naive(r, c)
+                        && grid.at(Pos::from_usize(r, c).unwrap()) == naive(r, c)
+                        && grid.in_bounds(r, c) == naive(r, c).is_some()
+                        && grid.wrapped_get(r, c) == &rows[r % height][c % width]
+                })
+            });
+            let negatives_missing = (-1..=height as i32).all(|r| grid.get_int(r, -1).is_none())
+                && (-1..=width as i32).all(|c| grid.get_int(-1, c).is_none());
+            grid.height() == height
+                && grid.width() == width
+                && cells_match
+                && negatives_missing
+                && grid.iter().eq(rows.iter().flatten())
+        });
+    }
+
+    #[test]
+    fn test_prop_index_mut() {
+        prop::check(&rows(), |rows| {
+            let mut grid = Grid::from_rows(rows.clone());
+            let mut naive = rows.clone();
+            for r in 0..rows.len() {
+                for c in (0..rows[0].len()).filter(|c| (r + c) % 3 == 0) {
+                    grid[(r, c)] += 10;
+                    naive[r][c] += 10;
+                }
+            }
+            grid.iter().eq(naive.iter().flatten())
+        });
+    }
 }
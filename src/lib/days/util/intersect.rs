@@ -19,6 +19,8 @@ pub fn count_intersect<T: Ord>(a: &[T], b: &[T]) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::prop;
+    use std::collections::HashSet;
 
     #[test]
     fn test_count_intersect() {
@@ -29,4 +31,22 @@ mod tests {
         assert_eq!(count_intersect(&vec![1, 2, 3], &vec![1, 2, 3, 4]), 3);
         assert_eq!(count_intersect(&vec![1, 2, 3], &vec![4, 5, 6]), 0);
     }
+
+    #[test]
+    fn test_prop_matches_hash_set() {
+        // count_intersect expects sorted input without repeats
+        let sorted_unique = |v: &Vec<i64>| {
+            let mut v = v.clone();
+            v.sort_unstable();
+            v.dedup();
+            v
+        };
+        let values = || prop::vec_of(prop::ints(0..=20), 0..=15);
+        prop::check(&prop::zip(values(), values()), |(a, b)| {
+            let (a, b) = (sorted_unique(a), sorted_unique(b));
+            let a_set: HashSet<&i64> = a.iter().collect();
+            let b_set: HashSet<&i64> = b.iter().collect();
+            count_intersect(&a, &b) == a_set.intersection(&b_set).count()
+        });
+    }
 }
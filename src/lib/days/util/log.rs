@@ -0,0 +1,198 @@
+// Diagnostics for solvers. Messages carry a level and the module that logged
+// them (e.g. `y2021::day23::puzzle`), and go to whatever sink the runner
+// installed. Nothing is installed by default, so logging is silent and costs
+// one atomic load per call site.
+//
+//   crate::debug!("region count {}", regions.len());
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+impl Level {
+    pub fn parse(s: &str) -> Option<Level> {
+        match s {
+            "error" => Some(Level::Error),
+            "warn" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+}
+
+/// Where log lines are written
+pub enum Sink {
+    Silent,
+    Stderr,
+    File(File),
+}
+
+/// Which messages are kept: a default level plus levels for targets such as
+/// a year `y2021` or a day `y2021::day23`, written `info,y2021::day23=trace`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Level)>,
+}
+impl Filter {
+    pub fn parse(spec: &str) -> Result<Filter, String> {
+        let mut filter = Filter::default();
+        for directive in spec.split(',').map(|d| d.trim()).filter(|d| !d.is_empty()) {
+            let level = |s: &str| Level::parse(s).ok_or_else(|| format!("unknown log level {}", s));
+            match directive.split_once('=') {
+                Some((target, l)) => filter.targets.push((target.to_string(), level(l)?)),
+                None => filter.default = Some(level(directive)?),
+            }
+        }
+        Ok(filter)
+    }
+
+    /// Level for a target, from the longest matching target prefix.
+    /// Targets are module paths without the crate name, e.g. `y2021::day23::puzzle`.
+    pub fn level_for(&self, target: &str) -> Option<Level> {
+        let matches = |prefix: &str| {
+            target == prefix
+                || target
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| rest.starts_with("::"))
+        };
+        self.targets
+            .iter()
+            .filter(|(prefix, _)| matches(prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, level)| *level)
+            .or(self.default)
+    }
+
+    fn max_level(&self) -> Option<Level> {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .chain(self.default)
+            .max()
+    }
+}
+
+/// Most verbose level any target is logging at, 0 when logging is off
+static MAX_LEVEL: AtomicUsize = AtomicUsize::new(0);
+static LOGGER: Mutex<Option<(Filter, Sink)>> = Mutex::new(None);
+
+/// Install the sink and filter for all later messages, replacing any earlier
+pub fn init(filter: Filter, sink: Sink) {
+    let max_level = match sink {
+        Sink::Silent => 0,
+        _ => filter.max_level().map_or(0, |level| level as usize),
+    };
+    *LOGGER.lock().unwrap() = Some((filter, sink));
+    MAX_LEVEL.store(max_level, Ordering::Relaxed);
+}
+
+/// Cheap check used by the macros before formatting anything
+pub fn enabled(level: Level) -> bool {
+    level as usize <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Write one message for `module`, as given by `module_path!`
+pub fn write(level: Level, module: &str, args: fmt::Arguments) {
+    let target = module.split_once("::").map_or(module, |(_, path)| path);
+    let mut logger = LOGGER.lock().unwrap();
+    let Some((filter, sink)) = logger.as_mut() else {
+        return;
+    };
+    if filter.level_for(target).is_none_or(|max| level > max) {
+        return;
+    }
+    let line = format!("{:<5} {}: {}\n", level.label(), target, args);
+    // losing a diagnostic isn't worth failing a solver over
+    let _ = match sink {
+        Sink::Silent => Ok(()),
+        Sink::Stderr => io::stderr().write_all(line.as_bytes()),
+        Sink::File(file) => file.write_all(line.as_bytes()),
+    };
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::util::log::enabled($level) {
+            $crate::util::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::util::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::util::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::util::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::util::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::util::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter =
+            Filter::parse("warn,y2021::day23=trace,y2021::day23::puzzle::parser=error").unwrap();
+        assert_eq!(filter.level_for("y2021::day11"), Some(Level::Warn));
+        assert_eq!(filter.level_for("y2021::day23"), Some(Level::Trace));
+        assert_eq!(filter.level_for("y2021::day23::puzzle"), Some(Level::Trace));
+        assert_eq!(
+            filter.level_for("y2021::day23::puzzle::parser"),
+            Some(Level::Error)
+        );
+        assert_eq!(filter.level_for("y2021::day231"), Some(Level::Warn));
+        assert_eq!(filter.max_level(), Some(Level::Trace));
+
+        let filter = Filter::parse("y2021=debug").unwrap();
+        assert_eq!(
+            filter.level_for("y2021::day22::puzzle::part_two"),
+            Some(Level::Debug)
+        );
+        assert_eq!(filter.level_for("y2022::day22"), None);
+
+        assert!(Filter::parse("loud").is_err());
+        assert!(Filter::parse("y2021::day22=").is_err());
+    }
+}
@@ -1,3 +1,9 @@
+pub mod error;
 pub mod grid;
 pub mod intersect;
+pub mod log;
 pub mod nom_helpers;
+pub mod pos;
+#[cfg(test)]
+pub mod prop;
+pub mod sparse_grid;
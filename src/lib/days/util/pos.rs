@@ -0,0 +1,173 @@
+// Coordinates on a grid. A `Pos` is a (row, col) with rows growing downwards
+// and a `Dir` one of the eight compass directions, north being up. Positions
+// are signed, so a step off the edge of a grid is still a position, just not
+// one on the grid; `Grid` methods taking a `Pos` check that for you.
+
+use std::fmt;
+use std::ops::{Add, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub row: i32,
+    pub col: i32,
+}
+impl Pos {
+    pub const ORIGIN: Pos = Pos { row: 0, col: 0 };
+
+    pub const fn new(row: i32, col: i32) -> Pos {
+        Pos { row, col }
+    }
+
+    /// Position of a grid cell, None if it doesn't fit
+    pub fn from_usize(row: usize, col: usize) -> Option<Pos> {
+        Some(Pos::new(row.try_into().ok()?, col.try_into().ok()?))
+    }
+
+    /// Row and column as grid indices, None if either is negative
+    pub fn to_usize(self) -> Option<(usize, usize)> {
+        Some((self.row.try_into().ok()?, self.col.try_into().ok()?))
+    }
+
+    pub fn checked_add(self, other: Pos) -> Option<Pos> {
+        Some(Pos::new(
+            self.row.checked_add(other.row)?,
+            self.col.checked_add(other.col)?,
+        ))
+    }
+
+    /// The position next to this one towards `dir`
+    pub fn checked_step(self, dir: Dir) -> Option<Pos> {
+        self.checked_add(dir.offset())
+    }
+}
+impl Add for Pos {
+    type Output = Pos;
+
+    fn add(self, other: Pos) -> Pos {
+        Pos::new(self.row + other.row, self.col + other.col)
+    }
+}
+impl Sub for Pos {
+    type Output = Pos;
+
+    fn sub(self, other: Pos) -> Pos {
+        Pos::new(self.row - other.row, self.col - other.col)
+    }
+}
+impl Add<Dir> for Pos {
+    type Output = Pos;
+
+    fn add(self, dir: Dir) -> Pos {
+        self + dir.offset()
+    }
+}
+impl From<(i32, i32)> for Pos {
+    fn from((row, col): (i32, i32)) -> Pos {
+        Pos::new(row, col)
+    }
+}
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+impl Dir {
+    /// Clockwise from north
+    pub const ALL: [Dir; 8] = [
+        Dir::N,
+        Dir::NE,
+        Dir::E,
+        Dir::SE,
+        Dir::S,
+        Dir::SW,
+        Dir::W,
+        Dir::NW,
+    ];
+    pub const ORTHOGONAL: [Dir; 4] = [Dir::N, Dir::E, Dir::S, Dir::W];
+    pub const DIAGONAL: [Dir; 4] = [Dir::NE, Dir::SE, Dir::SW, Dir::NW];
+
+    /// One step this way, as a change of (row, col)
+    pub fn offset(self) -> Pos {
+        match self {
+            Dir::N => Pos::new(-1, 0),
+            Dir::NE => Pos::new(-1, 1),
+            Dir::E => Pos::new(0, 1),
+            Dir::SE => Pos::new(1, 1),
+            Dir::S => Pos::new(1, 0),
+            Dir::SW => Pos::new(1, -1),
+            Dir::W => Pos::new(0, -1),
+            Dir::NW => Pos::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        Dir::DIAGONAL.contains(&self)
+    }
+
+    /// Turn clockwise by `eighths` of a full turn, counterclockwise when
+    /// negative
+    pub fn turn(self, eighths: i32) -> Dir {
+        Dir::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    /// A quarter turn clockwise
+    pub fn right(self) -> Dir {
+        self.turn(2)
+    }
+
+    /// A quarter turn counterclockwise
+    pub fn left(self) -> Dir {
+        self.turn(-2)
+    }
+
+    pub fn reverse(self) -> Dir {
+        self.turn(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turning() {
+        assert_eq!(Dir::N.right(), Dir::E);
+        assert_eq!(Dir::N.left(), Dir::W);
+        assert_eq!(Dir::NW.right(), Dir::NE);
+        assert_eq!(Dir::SE.reverse(), Dir::NW);
+        assert_eq!(Dir::E.turn(-1), Dir::NE);
+        assert_eq!(Dir::W.turn(10), Dir::N);
+        for dir in Dir::ALL {
+            assert_eq!(dir.offset() + dir.reverse().offset(), Pos::ORIGIN);
+            assert_eq!(dir.left().right(), dir);
+            let Pos { row, col } = dir.offset();
+            assert_eq!(dir.is_diagonal(), row != 0 && col != 0);
+        }
+    }
+
+    #[test]
+    fn test_checked() {
+        let pos = Pos::new(2, 3);
+        assert_eq!(pos + Dir::NW, Pos::new(1, 2));
+        assert_eq!(pos.checked_step(Dir::S), Some(Pos::new(3, 3)));
+        assert_eq!(Pos::new(0, i32::MAX).checked_step(Dir::E), None);
+        assert_eq!(Pos::new(i32::MIN, 0).checked_step(Dir::N), None);
+
+        assert_eq!(Pos::new(-1, 0).to_usize(), None);
+        assert_eq!(pos.to_usize(), Some((2, 3)));
+        assert_eq!(Pos::from_usize(2, 3), Some(pos));
+        assert_eq!(Pos::from_usize(usize::MAX, 0), None);
+    }
+}
@@ -0,0 +1,279 @@
+// Property tests: check an invariant against many random inputs and, when it
+// fails, shrink the input to a small counterexample before reporting it. Runs
+// are deterministic; set PROP_SEED to try other inputs and PROP_CASES to try
+// more of them.
+//
+//   prop::check(&prop::vec_of(prop::ints(0..=9), 0..=10), |v| v.len() <= 10);
+
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+const DEFAULT_SEED: u64 = 2021;
+const DEFAULT_CASES: usize = 200;
+/// Bound on shrinking steps, in case a generator shrinks in circles
+const MAX_SHRINKS: usize = 10_000;
+
+/// splitmix64, which is plenty for test inputs
+pub struct Rng {
+    state: u64,
+}
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform over `range`, which can't be empty
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "empty range {}..={}", lo, hi);
+        let span = (hi as i128 - lo as i128 + 1) as u128;
+        (lo as i128 + (self.next_u64() as u128 % span) as i128) as i64
+    }
+
+    pub fn len(&mut self, range: RangeInclusive<usize>) -> usize {
+        self.range(*range.start() as i64..=*range.end() as i64) as usize
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+}
+
+/// Random values of one type, and how to make a failing one smaller
+pub trait Gen {
+    type Value: Clone + Debug;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Smaller variants of `value`, most aggressive first, none once it's
+    /// as small as it gets
+    fn shrink(&self, _value: &Self::Value) -> Vec<Self::Value> {
+        vec![]
+    }
+}
+
+/// Integers in a range, shrinking towards 0 or the bound nearest to it
+pub struct Ints(RangeInclusive<i64>);
+
+pub fn ints(range: RangeInclusive<i64>) -> Ints {
+    Ints(range)
+}
+
+impl Gen for Ints {
+    type Value = i64;
+
+    fn generate(&self, rng: &mut Rng) -> i64 {
+        rng.range(self.0.clone())
+    }
+
+    fn shrink(&self, value: &i64) -> Vec<i64> {
+        let target = 0.clamp(*self.0.start(), *self.0.end());
+        let mut candidates = vec![
+            target,
+            target + (value - target) / 2,
+            value - (value - target).signum(),
+        ];
+        candidates.dedup();
+        candidates.retain(|c| c != value);
+        candidates
+    }
+}
+
+/// Vectors with a length in a range, shrinking by dropping elements and then
+/// by shrinking the elements
+pub struct VecOf<G> {
+    elem: G,
+    len: RangeInclusive<usize>,
+}
+
+pub fn vec_of<G: Gen>(elem: G, len: RangeInclusive<usize>) -> VecOf<G> {
+    VecOf { elem, len }
+}
+
+impl<G: Gen> Gen for VecOf<G> {
+    type Value = Vec<G::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let len = rng.len(self.len.clone());
+        (0..len).map(|_| self.elem.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let mut candidates = vec![];
+        let min = *self.len.start();
+        if value.len() > min {
+            let half = (value.len() / 2).max(min);
+            if half < value.len() - 1 {
+                candidates.push(value[..half].to_vec());
+                candidates.push(value[value.len() - half..].to_vec());
+            }
+            for i in 0..value.len() {
+                let mut smaller = value.clone();
+                smaller.remove(i);
+                candidates.push(smaller);
+            }
+        }
+        for (i, elem) in value.iter().enumerate() {
+            for smaller_elem in self.elem.shrink(elem) {
+                let mut smaller = value.clone();
+                smaller[i] = smaller_elem;
+                candidates.push(smaller);
+            }
+        }
+        candidates
+    }
+}
+
+/// Pairs of independent values, shrinking one side at a time
+pub struct Zip<A, B>(A, B);
+
+pub fn zip<A: Gen, B: Gen>(a: A, b: B) -> Zip<A, B> {
+    Zip(a, b)
+}
+
+impl<A: Gen, B: Gen> Gen for Zip<A, B> {
+    type Value = (A::Value, B::Value);
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let a = self.0.generate(rng);
+        (a, self.1.generate(rng))
+    }
+
+    fn shrink(&self, (a, b): &Self::Value) -> Vec<Self::Value> {
+        let left = self.0.shrink(a).into_iter().map(|a| (a, b.clone()));
+        let right = self.1.shrink(b).into_iter().map(|b| (a.clone(), b));
+        left.chain(right).collect()
+    }
+}
+
+/// A generator for structures the combinators don't cover
+pub struct FromFn<G, S> {
+    generate: G,
+    shrink: S,
+}
+
+pub fn from_fn<T, G, S>(generate: G, shrink: S) -> FromFn<G, S>
+where
+    G: Fn(&mut Rng) -> T,
+    S: Fn(&T) -> Vec<T>,
+{
+    FromFn { generate, shrink }
+}
+
+impl<T, G, S> Gen for FromFn<G, S>
+where
+    T: Clone + Debug,
+    G: Fn(&mut Rng) -> T,
+    S: Fn(&T) -> Vec<T>,
+{
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        (self.generate)(rng)
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        (self.shrink)(value)
+    }
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    std::env::var(name)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
+}
+
+/// First of `cases` inputs from `seed` that `prop` rejects, shrunk as far as
+/// it still fails, along with the input as generated
+pub fn counterexample<G, P>(
+    gen: &G,
+    seed: u64,
+    cases: usize,
+    prop: P,
+) -> Option<(G::Value, G::Value)>
+where
+    G: Gen,
+    P: Fn(&G::Value) -> bool,
+{
+    let mut rng = Rng::new(seed);
+    let failing = (0..cases)
+        .map(|_| gen.generate(&mut rng))
+        .find(|value| !prop(value))?;
+
+    // greedily take the first smaller input that still fails
+    let mut shrunk = failing.clone();
+    for _ in 0..MAX_SHRINKS {
+        match gen.shrink(&shrunk).into_iter().find(|value| !prop(value)) {
+            Some(smaller) => shrunk = smaller,
+            None => break,
+        }
+    }
+    Some((failing, shrunk))
+}
+
+/// Panic with a shrunk counterexample if `prop` rejects any generated input
+pub fn check<G, P>(gen: &G, prop: P)
+where
+    G: Gen,
+    P: Fn(&G::Value) -> bool,
+{
+    let seed = env_or("PROP_SEED", DEFAULT_SEED);
+    let cases = env_or("PROP_CASES", DEFAULT_CASES);
+    if let Some((failing, shrunk)) = counterexample(gen, seed, cases, prop) {
+        panic!(
+            "property failed with PROP_SEED={}\n  input:  {:?}\n  shrunk: {:?}",
+            seed, failing, shrunk
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_range() {
+        let mut rng = Rng::new(1);
+        let values: Vec<i64> = (0..1000).map(|_| rng.range(-3..=3)).collect();
+        assert!(values.iter().all(|v| (-3..=3).contains(v)));
+        assert!((-3..=3).all(|n| values.contains(&n)));
+        // the full range doesn't overflow
+        Rng::new(7).range(i64::MIN..=i64::MAX);
+    }
+
+    #[test]
+    fn test_shrink_ints() {
+        assert_eq!(ints(-10..=10).shrink(&8), vec![0, 4, 7]);
+        assert_eq!(ints(-10..=10).shrink(&-1), vec![0]);
+        assert_eq!(ints(3..=10).shrink(&3), vec![]);
+        assert_eq!(ints(3..=10).shrink(&5), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_counterexample() {
+        let gen = vec_of(ints(0..=100), 0..=20);
+        let sum_below = |v: &Vec<i64>| v.iter().sum::<i64>() < 50;
+        let (failing, shrunk) = counterexample(&gen, 3, 100, sum_below).unwrap();
+        assert!(!sum_below(&failing));
+        assert_eq!(shrunk.iter().sum::<i64>(), 50);
+        assert!(shrunk.len() <= 2);
+
+        assert_eq!(counterexample(&gen, 3, 100, |v| v.len() <= 20), None);
+    }
+
+    #[test]
+    fn test_shrink_respects_min_len() {
+        let gen = vec_of(ints(0..=9), 3..=5);
+        let (_, shrunk) = counterexample(&gen, 1, 100, |_| false).unwrap();
+        assert_eq!(shrunk, vec![0, 0, 0]);
+    }
+}
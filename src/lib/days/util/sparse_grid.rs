@@ -0,0 +1,161 @@
+// An unbounded grid for puzzles on an infinite plane. Only cells that differ
+// from the background are stored, keyed by signed `Pos`, so the grid can grow
+// in any direction; the bounding box of those cells is its extent. Convert to
+// a dense `Grid` for work that visits every cell.
+
+use crate::util::grid::Grid;
+use crate::util::pos::Pos;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+    background: T,
+}
+impl<T: Clone + PartialEq> SparseGrid<T> {
+    /// Every cell `background`
+    pub fn new(background: T) -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            background,
+        }
+    }
+
+    /// The cells of `grid` that aren't `background`, with the grid's top-left
+    /// corner placed at `top_left`
+    pub fn from_grid(grid: &Grid<T>, top_left: Pos, background: T) -> SparseGrid<T> {
+        let mut sparse = SparseGrid::new(background);
+        for pos in grid.positions() {
+            sparse.set(top_left + pos, grid[pos].clone());
+        }
+        sparse
+    }
+
+    pub fn background(&self) -> &T {
+        &self.background
+    }
+
+    pub fn get(&self, pos: Pos) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.background)
+    }
+
+    /// Setting a cell to the background forgets it
+    pub fn set(&mut self, pos: Pos, value: T) {
+        if value == self.background {
+            self.cells.remove(&pos);
+        } else {
+            self.cells.insert(pos, value);
+        }
+    }
+
+    /// The number of cells that aren't background
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The cells that aren't background, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(pos, value)| (*pos, value))
+    }
+
+    /// Top-left and bottom-right corners of the smallest box holding every
+    /// cell that isn't background, None if there are none
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        let mut positions = self.cells.keys();
+        let first = *positions.next()?;
+        Some(positions.fold((first, first), |(min, max), pos| {
+            (
+                Pos::new(min.row.min(pos.row), min.col.min(pos.col)),
+                Pos::new(max.row.max(pos.row), max.col.max(pos.col)),
+            )
+        }))
+    }
+
+    /// The cells from `top_left` to `bottom_right` inclusive as a dense grid,
+    /// empty if `bottom_right` is above or left of `top_left`
+    pub fn to_grid_within(&self, top_left: Pos, bottom_right: Pos) -> Grid<T> {
+        let span = |from: i32, to: i32| (to - from + 1).max(0) as usize;
+        let (ncols, nrows) = (
+            span(top_left.col, bottom_right.col),
+            span(top_left.row, bottom_right.row),
+        );
+        if ncols == 0 || nrows == 0 {
+            return Grid::empty();
+        }
+        Grid::from_fn(ncols, nrows, |row, col| {
+            self.get(top_left + Pos::new(row as i32, col as i32))
+                .clone()
+        })
+    }
+
+    /// The bounding box as a dense grid, None if every cell is background
+    pub fn to_grid(&self) -> Option<Grid<T>> {
+        let (top_left, bottom_right) = self.bounds()?;
+        Some(self.to_grid_within(top_left, bottom_right))
+    }
+}
+/// Cells on a background of `T::default()`
+impl<T: Clone + PartialEq + Default> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> SparseGrid<T> {
+        let mut sparse = SparseGrid::new(T::default());
+        for (pos, value) in iter {
+            sparse.set(pos, value);
+        }
+        sparse
+    }
+}
+/// The bounding box, one line per row; nothing if every cell is background
+impl<T: Clone + PartialEq + Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_grid() {
+            Some(grid) => write!(f, "{}", grid),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_bounds() {
+        let mut sparse = SparseGrid::new(0);
+        assert_eq!(sparse.bounds(), None);
+        assert_eq!(sparse.to_string(), "");
+
+        sparse.set(Pos::new(-2, 3), 1);
+        sparse.set(Pos::new(1, -1), 2);
+        sparse.set(Pos::new(5, 5), 3);
+        sparse.set(Pos::new(5, 5), 0);
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(*sparse.get(Pos::new(100, -100)), 0);
+        assert_eq!(sparse.bounds(), Some((Pos::new(-2, -1), Pos::new(1, 3))));
+        assert_eq!(sparse.to_string(), "00001\n00000\n00000\n20000\n");
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let grid = Grid::parse_bools(0, ".#.\n##.", '#', '.').unwrap();
+        let sparse = SparseGrid::from_grid(&grid, Pos::new(-1, 10), false);
+        assert_eq!(sparse.len(), 3);
+        assert!(*sparse.get(Pos::new(-1, 11)));
+        assert_eq!(sparse.bounds(), Some((Pos::new(-1, 10), Pos::new(0, 11))));
+
+        let dense = sparse
+            .to_grid_within(Pos::new(-1, 10), Pos::new(0, 12))
+            .map(|&b| b as u8);
+        assert_eq!(dense.to_string(), "010\n110\n");
+        let backwards = sparse.to_grid_within(Pos::new(-1, 10), Pos::new(0, 9));
+        assert_eq!((backwards.width(), backwards.height()), (0, 0));
+        assert_eq!(backwards.to_string(), "");
+
+        let collected: SparseGrid<bool> = sparse.iter().map(|(pos, &b)| (pos, b)).collect();
+        assert_eq!(collected, sparse);
+    }
+}
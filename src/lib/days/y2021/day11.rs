@@ -0,0 +1,248 @@
+const DAY: u32 = 11;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+",
+    answers: &[Some("1656"), Some("195")],
+}];
+
+mod puzzle {
+    use super::DAY;
+    use crate::util::error::AocError;
+    use crate::util::grid::Grid;
+    use crate::util::pos::{Dir, Pos};
+    use std::fmt;
+
+    #[derive(Debug)]
+    pub struct Octopus {
+        pub energy: u8,
+        pub flashed: bool,
+    }
+    impl fmt::Display for Octopus {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.energy)
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Cave {
+        octopi: Grid<Octopus>,
+        pub flashes: u64,
+    }
+    impl Cave {
+        pub fn width(&self) -> usize {
+            self.octopi.width()
+        }
+        pub fn height(&self) -> usize {
+            self.octopi.height()
+        }
+        pub fn get_at(&mut self, pos: Pos) -> &mut Octopus {
+            &mut self.octopi[pos]
+        }
+
+        pub fn step(&mut self) {
+            for octopus in self.octopi.iter_mut() {
+                octopus.energy += 1;
+            }
+
+            let mut cont = true;
+            while cont {
+                cont = false;
+                for pos in self.octopi.positions() {
+                    let octopus = self.get_at(pos);
+                    if octopus.energy > 9 && !octopus.flashed {
+                        octopus.flashed = true;
+                        self.flashes += 1;
+                        for dir in Dir::ALL {
+                            if let Some(neighbor) = self.octopi.step(pos, dir) {
+                                self.get_at(neighbor).energy += 1;
+                            }
+                        }
+                        cont = true;
+                    }
+                }
+            }
+
+            for octopus in self.octopi.iter_mut() {
+                if octopus.flashed {
+                    octopus.flashed = false;
+                    octopus.energy = 0;
+                }
+            }
+        }
+
+        pub fn all_zero(&self) -> bool {
+            self.octopi.iter().all(|o| o.energy == 0)
+        }
+
+        pub fn pretty_print(&self) -> String {
+            self.octopi.to_string().trim_end().to_string()
+        }
+    }
+
+    pub fn parse_input(input: &str) -> Result<Cave, AocError> {
+        let octopi = Grid::parse_digits(DAY, input)?.map(|&energy| Octopus {
+            energy,
+            flashed: false,
+        });
+        Ok(Cave { octopi, flashes: 0 })
+    }
+}
+
+use crate::session::{self, Command, Session};
+use crate::solution::{Answer, Error, Result};
+
+pub fn step_cave(input: &str, steps: usize) -> Result<u64> {
+    let mut cave = puzzle::parse_input(input)?;
+    //println!("staring config\n{}", cave.pretty_print());
+    for _n in 0..steps {
+        cave.step();
+        //println!("step {}\n{}", n+1, cave.pretty_print());
+    }
+    return Ok(cave.flashes.clone());
+}
+
+pub fn step_until_synchronized(cave: &mut puzzle::Cave) -> u64 {
+    let mut n: u64 = 0;
+    loop {
+        cave.step();
+        n += 1;
+        if cave.all_zero() {
+            break;
+        }
+    }
+    n
+}
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    step_cave(input, 100).map(Answer::from)
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    Ok(step_until_synchronized(&mut puzzle::parse_input(input)?).into())
+}
+
+/// Steps the cave by hand, to watch the flashes spread
+struct CaveSession {
+    cave: puzzle::Cave,
+    steps: u64,
+}
+impl Session for CaveSession {
+    fn commands(&self) -> &'static [Command] {
+        &[Command {
+            name: "step",
+            args: "[N]",
+            help: "run N steps, 1 by default",
+        }]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> std::result::Result<String, String> {
+        match command {
+            "step" => {
+                for _ in 0..session::count(args)? {
+                    self.cave.step();
+                    self.steps += 1;
+                }
+                Ok(self.show())
+            }
+            _ => Err(session::unknown(command)),
+        }
+    }
+
+    fn show(&self) -> String {
+        format!(
+            "after step {}, {} flashes\n{}",
+            self.steps,
+            self.cave.flashes,
+            self.cave.pretty_print()
+        )
+    }
+}
+
+pub struct Day11;
+impl crate::solution::Solution for Day11 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![part_one, part_two]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| puzzle::parse_input(input).map(drop).map_err(Error::from))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn session(&self) -> Option<session::Start> {
+        Some(|input| {
+            let cave = puzzle::parse_input(input)?;
+            Ok(Box::new(CaveSession { cave, steps: 0 }))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_example() {
+        assert_eq!(part_one(EXAMPLES[0].input), Ok(Answer::Integer(1656)));
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        assert_eq!(part_two(EXAMPLES[0].input), Ok(Answer::Integer(195)));
+    }
+
+    #[test]
+    fn test_small_example() {
+        let example = r#"
+      11111
+      19991
+      19191
+      19991
+      11111
+    "#;
+        assert_eq!(step_cave(example, 2), Ok(9));
+    }
+
+    #[test]
+    fn test_session() {
+        use crate::solution::Solution;
+
+        let start = Day11.session().unwrap();
+        let mut session = start("11111\n19991\n19191\n19991\n11111\n").unwrap();
+        assert_eq!(
+            session.run("step", &[]),
+            Ok("after step 1, 9 flashes\n34543\n40004\n50005\n40004\n34543".to_string())
+        );
+        assert!(session
+            .run("step", &["2"])
+            .unwrap()
+            .starts_with("after step 3,"));
+        assert!(session.run("step", &["x"]).is_err());
+        assert!(session.run("jump", &[]).is_err());
+    }
+}
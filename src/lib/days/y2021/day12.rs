@@ -1,6 +1,66 @@
 extern crate itertools;
 
+const DAY: u32 = 12;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+",
+        answers: &[Some("10"), Some("36")],
+    },
+    Example {
+        input: "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc
+",
+        answers: &[Some("19"), Some("103")],
+    },
+    Example {
+        input: "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+",
+        answers: &[Some("226"), Some("3509")],
+    },
+];
+
 mod puzzle {
+    use super::DAY;
+    use crate::util::error::AocError;
     use itertools::Itertools;
     #[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord, Hash)]
     pub enum CaveType {
@@ -14,6 +74,7 @@ mod puzzle {
             match input {
                 "start" => Some(CaveType::Start),
                 "end" => Some(CaveType::End),
+                "" => None,
                 big if big.chars().all(|c| char::is_uppercase(c)) => {
                     Some(CaveType::Big(big.to_string()))
                 }
@@ -33,9 +94,7 @@ mod puzzle {
     }
     impl Connection {
         fn parse(input: &str) -> Option<Connection> {
-            let v: Vec<&str> = input.split("-").collect();
-            let a = v[0];
-            let b = v[1];
+            let (a, b) = input.split_once("-")?;
             Some(Connection {
                 a: CaveType::parse(a)?,
                 b: CaveType::parse(b)?,
@@ -137,16 +196,18 @@ mod puzzle {
         }
     }
 
-    pub fn parse_input(input: &str) -> Option<CaveSystem> {
-        let connections: Option<Vec<Connection>> = input
+    pub fn parse_input(input: &str) -> Result<CaveSystem, AocError> {
+        let connections = input
             .lines()
-            .map(|l| l.trim())
-            .filter(|l| l.len() != 0)
-            .map(|l| Connection::parse(l))
-            .collect();
-        Some(CaveSystem {
-            connections: connections?,
-        })
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty())
+            .map(|(i, l)| {
+                Connection::parse(l.trim()).ok_or_else(|| {
+                    AocError::on_line(DAY, i, l, "expected `cave-cave` with named caves")
+                })
+            })
+            .collect::<Result<Vec<Connection>, AocError>>()?;
+        Ok(CaveSystem { connections })
     }
 
     #[cfg(test)]
@@ -184,20 +245,32 @@ mod puzzle {
                     b: End
                 })
             );
+            assert_eq!(Connection::parse("start"), None);
+            assert_eq!(Connection::parse("start-"), None);
+        }
+
+        #[test]
+        fn test_parse_input_error() {
+            let e = parse_input("start-A\n  A-b\n  b_end\n").err().unwrap();
+            assert_eq!((e.line, e.column), (Some(3), Some(3)));
+            assert_eq!(e.text, "b_end");
         }
     }
 }
-pub fn part_one(input: &str) -> Option<u64> {
+
+use crate::solution::{Answer, Error, Result};
+
+pub fn part_one(input: &str) -> Result<Answer> {
     let system = puzzle::parse_input(input)?;
     let paths = system.valid_paths_count(|path: &puzzle::Path, v| path.entries(v) < 1);
-    Some(paths)
+    Ok(paths.into())
 }
 
 /*
 Improve running time here, try reducing memory usage by only storing visit counts and last vertex instead of entire path.
 (Still going to have b^d running time and space?)
 */
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &str) -> Result<Answer> {
     let system = puzzle::parse_input(input)?;
     let paths = system.valid_paths_count(|path: &puzzle::Path, v| {
         if path.max_small_entries().unwrap_or(0) >= 2 {
@@ -206,7 +279,30 @@ pub fn part_two(input: &str) -> Option<u64> {
             path.entries(v) < 2
         }
     });
-    Some(paths)
+    Ok(paths.into())
+}
+
+pub struct Day12;
+impl crate::solution::Solution for Day12 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![part_one, part_two]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| puzzle::parse_input(input).map(drop).map_err(Error::from))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 #[cfg(test)]
@@ -221,61 +317,18 @@ mod tests {
     start,A,c,A,c,A,b,A,b,end
     start,A,c,A,c,A,b,A,b,A,end
     */
-    const EXAMPLE_1: &'static str = r#"
-    start-A
-    start-b
-    A-c
-    A-b
-    b-d
-    A-end
-    b-end
-  "#;
-
-    const EXAMPLE_2: &'static str = r#"
-    dc-end
-    HN-start
-    start-kj
-    dc-start
-    dc-HN
-    LN-dc
-    HN-end
-    kj-sa
-    kj-HN
-    kj-dc
-  "#;
-
-    const EXAMPLE_3: &'static str = r#"
-    fs-end
-    he-DX
-    fs-he
-    start-DX
-    pj-DX
-    end-zg
-    zg-sl
-    zg-pj
-    pj-he
-    RW-he
-    fs-DX
-    pj-RW
-    zg-RW
-    start-pj
-    he-WI
-    zg-he
-    pj-fs
-    start-RW
-  "#;
 
     #[test]
     fn test_part_one_examples() {
-        assert_eq!(part_one(EXAMPLE_1), Some(10));
-        assert_eq!(part_one(EXAMPLE_2), Some(19));
-        assert_eq!(part_one(EXAMPLE_3), Some(226));
+        assert_eq!(part_one(EXAMPLES[0].input), Ok(Answer::Integer(10)));
+        assert_eq!(part_one(EXAMPLES[1].input), Ok(Answer::Integer(19)));
+        assert_eq!(part_one(EXAMPLES[2].input), Ok(Answer::Integer(226)));
     }
 
     #[test]
     fn test_part_two_examples() {
-        assert_eq!(part_two(EXAMPLE_1), Some(36));
-        assert_eq!(part_two(EXAMPLE_2), Some(103));
-        assert_eq!(part_two(EXAMPLE_3), Some(3509));
+        assert_eq!(part_two(EXAMPLES[0].input), Ok(Answer::Integer(36)));
+        assert_eq!(part_two(EXAMPLES[1].input), Ok(Answer::Integer(103)));
+        assert_eq!(part_two(EXAMPLES[2].input), Ok(Answer::Integer(3509)));
     }
 }
@@ -0,0 +1,434 @@
+const DAY: u32 = 13;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+",
+    answers: &[Some("17"), None],
+}];
+
+mod puzzle {
+    use super::DAY;
+    use crate::util::error::AocError;
+    use crate::util::pos::Pos;
+    use crate::util::sparse_grid::SparseGrid;
+    use std::fmt;
+
+    /// A dot `x,y`, as the position of row y, column x
+    fn parse_dot(input: &str) -> Option<Pos> {
+        let (x, y) = input.split_once(",")?;
+        let coordinate = |s: &str| s.trim().parse::<u32>().ok()?.try_into().ok();
+        Some(Pos::new(coordinate(y)?, coordinate(x)?))
+    }
+
+    pub struct OrigamiPaper {
+        dots: SparseGrid<bool>,
+    }
+    impl OrigamiPaper {
+        fn parse_points(input: &[(usize, &str)]) -> Result<OrigamiPaper, AocError> {
+            let dots = input
+                .iter()
+                .map(|(i, s)| {
+                    parse_dot(s)
+                        .map(|pos| (pos, true))
+                        .ok_or_else(|| AocError::on_line(DAY, *i, s, "expected a dot `x,y`"))
+                })
+                .collect::<Result<SparseGrid<bool>, AocError>>()?;
+            Ok(OrigamiPaper { dots })
+        }
+
+        pub fn fold(&mut self, direction: &FoldDirection) {
+            // rebuild the grid, so dots landing on each other merge
+            self.dots = self
+                .dots
+                .iter()
+                .map(|(pos, _)| (direction.reflect(pos), true))
+                .collect();
+        }
+
+        pub fn count(&self) -> u64 {
+            self.dots.len() as u64
+        }
+
+        /// The paper from its top-left corner, which stays put while folding,
+        /// widened to take in any dots folded past it
+        pub fn pretty_print(&self) -> String {
+            let (top_left, bottom_right) = match self.dots.bounds() {
+                Some((min, max)) => (Pos::new(min.row.min(0), min.col.min(0)), max),
+                None => return String::new(),
+            };
+            let paper = self.dots.to_grid_within(top_left, bottom_right);
+            paper
+                .map(|&dot| if dot { '#' } else { '.' })
+                .to_string()
+                .trim_end()
+                .to_string()
+        }
+
+        /// Read the capital letters drawn by the dots, or None if the paper
+        /// isn't a row of known letters
+        pub fn read_letters(&self) -> Option<String> {
+            let rows: Vec<Vec<char>> = self
+                .pretty_print()
+                .lines()
+                .map(|l| l.chars().collect())
+                .collect();
+            if rows.len() != LETTER_HEIGHT {
+                return None;
+            }
+            let letters = (rows[0].len() + LETTER_WIDTH) / (LETTER_WIDTH + 1);
+            (0..letters)
+                .map(|i| {
+                    let glyph: String = rows
+                        .iter()
+                        .flat_map(|row| {
+                            (0..LETTER_WIDTH).map(move |dx| {
+                                *row.get(i * (LETTER_WIDTH + 1) + dx).unwrap_or(&'.')
+                            })
+                        })
+                        .collect();
+                    LETTERS
+                        .iter()
+                        .find(|(_, letter)| *letter == glyph)
+                        .map(|(c, _)| *c)
+                })
+                .collect()
+        }
+    }
+
+    // The puzzle font: letters are 4 dots wide and 6 high, separated by a
+    // blank column. Each glyph below is its rows concatenated.
+    const LETTER_WIDTH: usize = 4;
+    const LETTER_HEIGHT: usize = 6;
+    const LETTERS: [(char, &str); 18] = [
+        ('A', ".##.#..##..######..##..#"),
+        ('B', "###.#..####.#..##..####."),
+        ('C', ".##.#..##...#...#..#.##."),
+        ('E', "#####...###.#...#...####"),
+        ('F', "#####...###.#...#...#..."),
+        ('G', ".##.#..##...#.###..#.###"),
+        ('H', "#..##..######..##..##..#"),
+        ('I', ".###..#...#...#...#..###"),
+        ('J', "..##...#...#...##..#.##."),
+        ('K', "#..##.#.##..#.#.#.#.#..#"),
+        ('L', "#...#...#...#...#...####"),
+        ('O', ".##.#..##..##..##..#.##."),
+        ('P', "###.#..##..####.#...#..."),
+        ('R', "###.#..##..####.#.#.#..#"),
+        ('S', ".####...#....##....####."),
+        ('U', "#..##..##..##..##..#.##."),
+        ('Y', "#...#....#.#..#...#...#."),
+        ('Z', "####...#..#..#..#...####"),
+    ];
+
+    pub enum FoldDirection {
+        AlongX(i32),
+        AlongY(i32),
+    }
+    impl FoldDirection {
+        /// Where a dot ends up after this fold
+        pub fn reflect(&self, pos: Pos) -> Pos {
+            match *self {
+                FoldDirection::AlongX(x) if pos.col > x => Pos::new(pos.row, 2 * x - pos.col),
+                FoldDirection::AlongY(y) if pos.row > y => Pos::new(2 * y - pos.row, pos.col),
+                _ => pos,
+            }
+        }
+
+        fn parse_direction(input: &str) -> Option<FoldDirection> {
+            let instruction = input.trim().strip_prefix("fold along ")?;
+            let (axis, number) = instruction.split_once("=")?;
+            let number = number.parse::<u32>().ok()?.try_into().ok()?;
+            match axis {
+                "x" => Some(FoldDirection::AlongX(number)),
+                "y" => Some(FoldDirection::AlongY(number)),
+                _ => None,
+            }
+        }
+        fn parse_directions(inputs: &[(usize, &str)]) -> Result<Vec<FoldDirection>, AocError> {
+            inputs
+                .iter()
+                .map(|(i, s)| {
+                    FoldDirection::parse_direction(s).ok_or_else(|| {
+                        AocError::on_line(
+                            DAY,
+                            *i,
+                            s,
+                            "expected `fold along x=N` or `fold along y=N`",
+                        )
+                    })
+                })
+                .collect()
+        }
+    }
+
+    impl fmt::Display for FoldDirection {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                FoldDirection::AlongX(x) => write!(f, "fold along x={}", x),
+                FoldDirection::AlongY(y) => write!(f, "fold along y={}", y),
+            }
+        }
+    }
+
+    pub fn parse_input(input: &str) -> Result<(OrigamiPaper, Vec<FoldDirection>), AocError> {
+        let (points, directions): (Vec<_>, Vec<_>) = input
+            .lines()
+            .enumerate()
+            .filter(|(_, s)| !s.trim().is_empty())
+            .partition(|(_, s)| !s.contains("fold"));
+        Ok((
+            OrigamiPaper::parse_points(&points)?,
+            FoldDirection::parse_directions(&directions)?,
+        ))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_reflection() {
+            let p = FoldDirection::AlongX(2).reflect(Pos::new(0, 3));
+            assert_eq!(p.col, 1);
+
+            let p2 = FoldDirection::AlongY(2).reflect(Pos::new(3, 0));
+            assert_eq!(p2.row, 1);
+        }
+
+        #[test]
+        fn test_parse_errors() {
+            let e = parse_input("6,10\n0,14\n\nfold along\n").err().unwrap();
+            assert_eq!((e.line, e.column), (Some(4), Some(1)));
+            assert_eq!(e.text, "fold along");
+
+            let e = parse_input("6,10\n0\n").err().unwrap();
+            assert_eq!(e.line, Some(2));
+        }
+    }
+}
+
+use crate::session::{self, Command, Session};
+use crate::solution::{Answer, Error, Result};
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let (mut paper, directions) = puzzle::parse_input(input)?;
+    for direction in directions.iter().take(1) {
+        paper.fold(direction);
+    }
+    Ok(paper.count().into())
+}
+
+/// The code is the letters drawn by the fully folded paper. Paper that doesn't
+/// spell out known letters is returned as a drawing.
+pub fn part_two(input: &str) -> Result<Answer> {
+    let (mut paper, directions) = puzzle::parse_input(input)?;
+    for direction in directions {
+        paper.fold(&direction);
+    }
+    crate::debug!("folded paper:\n{}", paper.pretty_print());
+    Ok(match paper.read_letters() {
+        Some(letters) => Answer::Text(letters),
+        None => Answer::Lines(paper.pretty_print().lines().map(String::from).collect()),
+    })
+}
+
+/// Folds the paper one instruction at a time
+struct PaperSession {
+    paper: puzzle::OrigamiPaper,
+    directions: Vec<puzzle::FoldDirection>,
+    folded: usize,
+}
+impl Session for PaperSession {
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                name: "fold",
+                args: "[N]",
+                help: "make the next N folds, 1 by default",
+            },
+            Command {
+                name: "folds",
+                args: "",
+                help: "list the folds still to make",
+            },
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> std::result::Result<String, String> {
+        match command {
+            "fold" => {
+                let count = session::count(args)?;
+                if self.folded == self.directions.len() {
+                    return Err("no folds left".to_string());
+                }
+                let made: Vec<String> = self.directions[self.folded..]
+                    .iter()
+                    .take(count)
+                    .map(|direction| {
+                        self.paper.fold(direction);
+                        format!("{}: {} dots", direction, self.paper.count())
+                    })
+                    .collect();
+                self.folded += made.len();
+                Ok(made.join("\n"))
+            }
+            "folds" => Ok(self
+                .directions
+                .iter()
+                .skip(self.folded)
+                .map(|direction| direction.to_string())
+                .collect::<Vec<String>>()
+                .join("\n")),
+            _ => Err(session::unknown(command)),
+        }
+    }
+
+    fn show(&self) -> String {
+        format!(
+            "{} dots after {} of {} folds\n{}",
+            self.paper.count(),
+            self.folded,
+            self.directions.len(),
+            self.paper.pretty_print()
+        )
+    }
+}
+
+pub struct Day13;
+impl crate::solution::Solution for Day13 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![part_one, part_two]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| puzzle::parse_input(input).map(drop).map_err(Error::from))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn session(&self) -> Option<session::Start> {
+        Some(|input| {
+            let (paper, directions) = puzzle::parse_input(input)?;
+            Ok(Box::new(PaperSession {
+                paper,
+                directions,
+                folded: 0,
+            }))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_examples() {
+        assert_eq!(part_one(EXAMPLES[0].input), Ok(Answer::Integer(17)))
+    }
+
+    #[test]
+    fn test_session() {
+        use crate::solution::Solution;
+
+        let start = Day13.session().unwrap();
+        let mut session = start(EXAMPLES[0].input).unwrap();
+        assert_eq!(
+            session.run("folds", &[]),
+            Ok("fold along y=7\nfold along x=5".to_string())
+        );
+        assert_eq!(
+            session.run("fold", &[]),
+            Ok("fold along y=7: 17 dots".to_string())
+        );
+        assert_eq!(
+            session.run("fold", &["5"]),
+            Ok("fold along x=5: 16 dots".to_string())
+        );
+        assert!(session.run("fold", &[]).is_err());
+        assert!(session
+            .show()
+            .starts_with("16 dots after 2 of 2 folds\n#####\n"));
+    }
+
+    #[test]
+    fn test_part_two_example_is_a_drawing() {
+        let square = ["#####", "#...#", "#...#", "#...#", "#####"];
+        assert_eq!(
+            part_two(EXAMPLES[0].input),
+            Ok(Answer::Lines(
+                square.iter().map(|s| s.to_string()).collect()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_part_two_reads_letters() {
+        // "HI" drawn directly, folding along an empty line far to the right
+        let dots: Vec<String> = [
+            "#..#..###",
+            "#..#...#.",
+            "####...#.",
+            "#..#...#.",
+            "#..#...#.",
+            "#..#..###",
+        ]
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.char_indices()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(x, _)| format!("{},{}", x, y))
+        })
+        .collect();
+        let input = format!("{}\n\nfold along x=20", dots.join("\n"));
+        assert_eq!(part_two(&input), Ok(Answer::Text("HI".to_string())));
+    }
+
+    #[test]
+    fn test_part_two_folds_dots_past_the_edge() {
+        // the dot lands left of the origin, at x=-3
+        assert_eq!(
+            part_two("5,0\n\nfold along x=1\n"),
+            Ok(Answer::Lines(vec!["#".to_string()]))
+        );
+        assert_eq!(
+            part_two("0,0\n5,1\n\nfold along x=1\n"),
+            Ok(Answer::Lines(vec!["...#".to_string(), "#...".to_string()]))
+        );
+    }
+}
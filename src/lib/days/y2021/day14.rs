@@ -3,7 +3,38 @@ extern crate itertools;
 use itertools::Itertools;
 use itertools::MinMaxResult::MinMax;
 
+const DAY: u32 = 14;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C
+",
+    answers: &[Some("1588"), Some("2188189693529")],
+}];
+
 mod puzzle {
+    use super::DAY;
+    use crate::util::error::AocError;
     use itertools::Itertools;
     use std::collections::HashMap;
 
@@ -37,6 +68,9 @@ mod puzzle {
     }
     impl Polymer {
         pub fn parse(input: &str) -> Option<Polymer> {
+            if !input.chars().all(|c| c.is_ascii_uppercase()) {
+                return None;
+            }
             let pairs = input
                 .as_bytes()
                 .windows(2)
@@ -108,13 +142,13 @@ mod puzzle {
         }
 
         pub fn parse(input: &str) -> Option<PairInsertionRule> {
-            let parts: Vec<&str> = input.split_whitespace().collect();
-            let pair: Vec<char> = parts[0].chars().collect();
-            let insert: char = parts[2].chars().next()?;
+            let (pair, insert) = input.split_once("->")?;
+            let (left, right) = pair.trim().chars().collect_tuple()?;
+            let (insert,) = insert.trim().chars().collect_tuple()?;
 
             Some(PairInsertionRule {
-                left: Element { letter: pair[0] },
-                right: Element { letter: pair[1] },
+                left: Element { letter: left },
+                right: Element { letter: right },
                 insert: Element { letter: insert },
             })
         }
@@ -140,33 +174,46 @@ mod puzzle {
         }
     }
 
-    pub fn parse_input(input: &str) -> Option<(Polymer, Vec<PairInsertionRule>)> {
-        let mut lines_iter = input.lines().map(|s| s.trim()).filter(|s| s.len() != 0);
-
-        let start_polymer = Polymer::parse(lines_iter.next()?)?;
+    pub fn parse_input(input: &str) -> Result<(Polymer, Vec<PairInsertionRule>), AocError> {
+        let mut lines_iter = input
+            .lines()
+            .enumerate()
+            .filter(|(_, s)| !s.trim().is_empty());
+
+        let (i, template) = lines_iter
+            .next()
+            .ok_or_else(|| AocError::new(DAY, "missing polymer template"))?;
+        let start_polymer = Polymer::parse(template.trim()).ok_or_else(|| {
+            AocError::on_line(DAY, i, template, "expected a template of element letters")
+        })?;
         let rules = lines_iter
-            .map(|s| PairInsertionRule::parse(s))
-            .collect::<Option<Vec<PairInsertionRule>>>()?;
+            .map(|(i, s)| {
+                PairInsertionRule::parse(s)
+                    .ok_or_else(|| AocError::on_line(DAY, i, s, "expected a rule like `CH -> B`"))
+            })
+            .collect::<Result<Vec<PairInsertionRule>, AocError>>()?;
 
-        Some((start_polymer, rules))
+        Ok((start_polymer, rules))
     }
 }
 
-pub fn step_and_min_max(input: &str, steps: u32) -> Option<u64> {
+use crate::solution::{Answer, Error, Result};
+
+pub fn step_and_min_max(input: &str, steps: u32) -> Result<u64> {
     let (mut start_polymer, rules) = puzzle::parse_input(input)?;
 
     for _i in 0..steps {
         start_polymer.apply_rules(&rules);
     }
     if let MinMax(min, max) = start_polymer.elements().values().minmax() {
-        Some((max - min) as u64)
+        Ok((max - min) as u64)
     } else {
-        None
+        Err(Error::no_answer("polymer has fewer than two elements"))
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    step_and_min_max(input, 10)
+pub fn part_one(input: &str) -> Result<Answer> {
+    step_and_min_max(input, 10).map(Answer::from)
 }
 
 /*
@@ -177,8 +224,31 @@ pub fn part_one(input: &str) -> Option<u64> {
 - rule application is elements.contains((l,r))
 - rule production is li + count, ir + count
 */
-pub fn part_two(input: &str) -> Option<u64> {
-    step_and_min_max(input, 40)
+pub fn part_two(input: &str) -> Result<Answer> {
+    step_and_min_max(input, 40).map(Answer::from)
+}
+
+pub struct Day14;
+impl crate::solution::Solution for Day14 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![part_one, part_two]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| puzzle::parse_input(input).map(drop).map_err(Error::from))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 #[cfg(test)]
@@ -186,34 +256,17 @@ mod tests {
     use super::*;
     use puzzle::*;
 
-    const EXAMPLE: &'static str = r#"
-    NNCB
-
-    CH -> B
-    HH -> N
-    CB -> H
-    NH -> C
-    HB -> C
-    HC -> B
-    HN -> C
-    NN -> C
-    BH -> H
-    NC -> B
-    NB -> B
-    BN -> B
-    BB -> N
-    BC -> B
-    CC -> N
-    CN -> C
-  "#;
     #[test]
     fn test_example_part_one() {
-        assert_eq!(part_one(EXAMPLE), Some(1588));
+        assert_eq!(part_one(EXAMPLES[0].input), Ok(Answer::Integer(1588)));
     }
 
     #[test]
     fn test_example_part_two() {
-        assert_eq!(part_two(EXAMPLE), Some(2188189693529));
+        assert_eq!(
+            part_two(EXAMPLES[0].input),
+            Ok(Answer::Integer(2188189693529))
+        );
     }
 
     #[test]
@@ -0,0 +1,265 @@
+const DAY: u32 = 15;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+",
+    answers: &[Some("40"), Some("315")],
+}];
+
+mod puzzle {
+    use super::DAY;
+    use crate::util::error::AocError;
+    use crate::util::grid::{Grid, Neighborhood};
+    use crate::util::pos::Pos;
+    use std::cmp::Ordering;
+    use std::collections::BinaryHeap;
+
+    #[derive(Debug)]
+    pub struct CaveGraph {
+        properties: Grid<LocationProperties>,
+    }
+    impl CaveGraph {
+        pub fn top_left(&self) -> Pos {
+            Pos::ORIGIN
+        }
+        pub fn bottom_right(&self) -> Pos {
+            Pos::from_usize(self.properties.height() - 1, self.properties.width() - 1)
+                .expect("cave fits in i32 coordinates")
+        }
+        pub fn get_neighbors(&self, of: Pos) -> impl Iterator<Item = Pos> + '_ {
+            self.properties.neighbors_of(of, Neighborhood::VonNeumann)
+        }
+        pub fn get(&self, at: Pos) -> &LocationProperties {
+            &self.properties[at]
+        }
+        pub fn parse(input: &str) -> Result<CaveGraph, AocError> {
+            let properties = Grid::parse_digits(DAY, input)?.map(|&r| LocationProperties {
+                risk_level: r.into(),
+            });
+            Ok(CaveGraph { properties })
+        }
+
+        pub fn add_and_wrap(level: u64, plus: u64) -> u64 {
+            let wrapped_risk = level + plus;
+            let new_risk = if wrapped_risk > 9 {
+                wrapped_risk - 9
+            } else {
+                wrapped_risk
+            };
+            new_risk
+        }
+
+        pub fn parse_and_expand(input: &str) -> Result<CaveGraph, AocError> {
+            let tile = CaveGraph::parse(input)?.properties;
+            let properties = tile.tile(5, 5, |location, down, across| LocationProperties {
+                risk_level: CaveGraph::add_and_wrap(location.risk_level, (down + across) as u64),
+            });
+            Ok(CaveGraph { properties })
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct LocationProperties {
+        pub risk_level: u64,
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    struct State {
+        cost: u64,
+        position: Pos,
+    }
+
+    impl Ord for State {
+        fn cmp(&self, other: &Self) -> Ordering {
+            // Notice that the we flip the ordering on costs. (turn max-heap into min-heap)
+            // In case of a tie we compare positions - this step is necessary
+            // to make implementations of `PartialEq` and `Ord` consistent.
+            other
+                .cost
+                .cmp(&self.cost)
+                .then_with(|| self.position.cmp(&other.position))
+        }
+    }
+
+    // `PartialOrd` needs to be implemented as well.
+    impl PartialOrd for State {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    pub fn lowest_cost_between<F: Fn(&LocationProperties) -> u64>(
+        graph: CaveGraph,
+        cost_fun: F,
+        start: Pos,
+        end: Pos,
+    ) -> Option<u64> {
+        let mut dist: Grid<u64> = Grid::fill(
+            graph.properties.width(),
+            graph.properties.height(),
+            u64::MAX,
+        );
+
+        let mut heap = BinaryHeap::new();
+
+        dist[start] = 0;
+        heap.push(State {
+            cost: 0,
+            position: start,
+        });
+
+        while let Some(State { cost, position }) = heap.pop() {
+            // println!("{:?} {:?} {:?}", cost, dist[position], position);
+            if position == end {
+                return Some(cost);
+            }
+
+            // ignore more expensive path (if dist relaxed)
+            if cost > dist[position] {
+                continue;
+            }
+
+            for neighbor in graph.get_neighbors(position) {
+                let next = State {
+                    cost: cost + cost_fun(graph.get(neighbor)),
+                    position: neighbor,
+                };
+
+                if next.cost < dist[next.position] {
+                    heap.push(next);
+                    dist[next.position] = next.cost;
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn parse_input(input: &str) -> Result<CaveGraph, AocError> {
+        CaveGraph::parse(input)
+    }
+}
+
+use crate::solution::{Answer, Error, Result};
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    use puzzle::*;
+
+    let cave = parse_input(input)?;
+    let top_left = cave.top_left();
+    let bottom_right = cave.bottom_right();
+
+    lowest_cost_between(cave, |prop| prop.risk_level, top_left, bottom_right)
+        .map(Answer::from)
+        .ok_or_else(|| Error::no_answer("no path to the bottom right"))
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    use puzzle::*;
+
+    // was easier to debug this brute force approach, and also faster! ~3x
+    // if I had to guess the slowness was caused by using the HashMap for the dist
+    // map even though the graph was actually sparse (just implicit)
+    let cave = CaveGraph::parse_and_expand(input)?;
+    let top_left = cave.top_left();
+    let bottom_right = cave.bottom_right();
+
+    lowest_cost_between(cave, |prop| prop.risk_level, top_left, bottom_right)
+        .map(Answer::from)
+        .ok_or_else(|| Error::no_answer("no path to the bottom right"))
+}
+
+pub struct Day15;
+impl crate::solution::Solution for Day15 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![part_one, part_two]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| puzzle::parse_input(input).map(drop).map_err(Error::from))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part_one() {
+        assert_eq!(part_one(EXAMPLES[0].input), Ok(Answer::Integer(40)));
+    }
+
+    #[test]
+    fn test_example_part_two() {
+        assert_eq!(part_two(EXAMPLES[0].input), Ok(Answer::Integer(315)));
+    }
+
+    #[test]
+    fn test_hand_worked_example() {
+        let example = r#"
+    155
+    155
+    111
+    "#;
+        assert_eq!(part_one(example), Ok(Answer::Integer(4)));
+    }
+
+    #[test]
+    fn test_non_square_cave() {
+        assert_eq!(part_one("1111\n9991"), Ok(Answer::Integer(4)));
+        assert_eq!(part_one("19\n19\n11"), Ok(Answer::Integer(3)));
+    }
+
+    #[test]
+    fn test_hand_worked_part_two_examples() {
+        assert_eq!(
+            part_two("1"),
+            Ok(Answer::Integer(2 + 3 + 4 + 5 + 6 + 7 + 8 + 9))
+        );
+        assert_eq!(
+            part_two("2"),
+            Ok(Answer::Integer(3 + 4 + 5 + 6 + 7 + 8 + 9 + 1))
+        );
+        assert_eq!(
+            part_two("8"),
+            Ok(Answer::Integer(9 + 1 + 2 + 3 + 4 + 5 + 6 + 7))
+        );
+        assert_eq!(
+            part_two("11\n11"),
+            Ok(Answer::Integer(1 + 1 + (2 + 3 + 4 + 5 + 6 + 7 + 8 + 9) * 2))
+        );
+    }
+
+    #[test]
+    fn test_add_and_wrap() {
+        let wrapped = (1..10)
+            .map(|i| puzzle::CaveGraph::add_and_wrap(i, 1))
+            .collect::<Vec<_>>();
+        assert_eq!(wrapped, vec![2, 3, 4, 5, 6, 7, 8, 9, 1]);
+    }
+}
@@ -1,4 +1,63 @@
+const DAY: u32 = 16;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "8A004A801A8002F478",
+        answers: &[Some("16"), None],
+    },
+    Example {
+        input: "620080001611562C8802118E34",
+        answers: &[Some("12"), None],
+    },
+    Example {
+        input: "C0015000016115A2E0802F182340",
+        answers: &[Some("23"), None],
+    },
+    Example {
+        input: "A0016C880162017C3686B18A3D4780",
+        answers: &[Some("31"), None],
+    },
+    Example {
+        input: "C200B40A82",
+        answers: &[None, Some("3")],
+    },
+    Example {
+        input: "04005AC33890",
+        answers: &[None, Some("54")],
+    },
+    Example {
+        input: "880086C3E88112",
+        answers: &[None, Some("7")],
+    },
+    Example {
+        input: "CE00C43D881120",
+        answers: &[None, Some("9")],
+    },
+    Example {
+        input: "D8005AC2A8F0",
+        answers: &[None, Some("1")],
+    },
+    Example {
+        input: "F600BC2D8F",
+        answers: &[None, Some("0")],
+    },
+    Example {
+        input: "9C005AC2F8F0",
+        answers: &[None, Some("0")],
+    },
+    Example {
+        input: "9C0141080250320F1802104A08",
+        answers: &[None, Some("1")],
+    },
+];
+
 mod puzzle {
+    use super::DAY;
+    use crate::util::error::AocError;
+
     // These parsers would probably be easier to express with nom
     // https://github.com/Geal/nom
     pub enum Packet {
@@ -7,12 +66,12 @@ mod puzzle {
     }
     impl Packet {
         pub fn maybe_parse(input: &mut HexStringReader) -> Option<Packet> {
-            let version = input.read_bits(3);
-            let typ: OperatorType = input.read_bits(3).try_into().ok()?;
+            let version = input.read_bits(3)?;
+            let typ: OperatorType = input.read_bits(3)?.try_into().ok()?;
             //println!("v {} t {}", version, typ);
             match typ {
-                OperatorType::Literal => Some(Packet::Lit(Literal::parse(version, input))),
-                _ => Some(Packet::Op(Operator::parse(version, typ, input))),
+                OperatorType::Literal => Some(Packet::Lit(Literal::parse(version, input)?)),
+                _ => Some(Packet::Op(Operator::parse(version, typ, input)?)),
             }
         }
         pub fn version(&self) -> u8 {
@@ -38,83 +97,30 @@ mod puzzle {
             }
         }
 
-        pub fn evaluate(&self) -> u64 {
-            match self {
-                Packet::Lit(Literal { value: v, .. }) => v.clone(),
-                Packet::Op(Operator {
-                    type_id: OperatorType::Sum,
-                    sub_packets: packets,
-                    ..
-                }) => packets
-                    .iter()
-                    .map(|p| Packet::evaluate(p))
-                    .fold(0, |acc, b| acc + b),
-                Packet::Op(Operator {
-                    type_id: OperatorType::Product,
-                    sub_packets: packets,
-                    ..
-                }) => packets
-                    .iter()
-                    .map(|p| Packet::evaluate(p))
-                    .fold(1, |acc, b| acc * b),
-                Packet::Op(Operator {
-                    type_id: OperatorType::Minimum,
-                    sub_packets: packets,
-                    ..
-                }) => packets
-                    .iter()
-                    .map(|p| Packet::evaluate(p))
-                    .min()
-                    .unwrap_or(0),
-                Packet::Op(Operator {
-                    type_id: OperatorType::Maximum,
-                    sub_packets: packets,
-                    ..
-                }) => packets
-                    .iter()
-                    .map(|p| Packet::evaluate(p))
-                    .max()
-                    .unwrap_or(0),
-                Packet::Op(Operator {
-                    type_id: OperatorType::LessThan,
-                    sub_packets: packets,
-                    ..
-                }) => {
-                    let ps: Vec<u64> = packets.iter().map(|p| Packet::evaluate(p)).collect();
-                    if ps[0] < ps[1] {
-                        1
-                    } else {
-                        0
-                    }
-                }
-                Packet::Op(Operator {
-                    type_id: OperatorType::GreaterThan,
-                    sub_packets: packets,
-                    ..
-                }) => {
-                    let ps: Vec<u64> = packets.iter().map(|p| Packet::evaluate(p)).collect();
-                    if ps[0] > ps[1] {
-                        1
-                    } else {
-                        0
-                    }
-                }
-                Packet::Op(Operator {
-                    type_id: OperatorType::EqualTo,
-                    sub_packets: packets,
-                    ..
-                }) => {
-                    let ps: Vec<u64> = packets.iter().map(|p| Packet::evaluate(p)).collect();
-                    if ps[0] == ps[1] {
-                        1
-                    } else {
-                        0
-                    }
-                }
-                Packet::Op(Operator {
-                    type_id: OperatorType::Literal,
-                    ..
-                }) => unreachable!(),
+        /// Fails if a comparison packet doesn't hold exactly two sub-packets
+        pub fn evaluate(&self) -> Result<u64, &'static str> {
+            let op = match self {
+                Packet::Lit(Literal { value: v, .. }) => return Ok(*v),
+                Packet::Op(op) => op,
+            };
+            let values = op
+                .sub_packets
+                .iter()
+                .map(Packet::evaluate)
+                .collect::<Result<Vec<u64>, _>>()?;
+            let compare = |f: fn(&u64, &u64) -> bool| match values[..] {
+                [a, b] => Ok(f(&a, &b) as u64),
+                _ => Err("comparison packet without exactly two sub-packets"),
+            };
+            match op.type_id {
+                OperatorType::Sum => Ok(values.iter().sum()),
+                OperatorType::Product => Ok(values.iter().product()),
+                OperatorType::Minimum => Ok(values.iter().copied().min().unwrap_or(0)),
+                OperatorType::Maximum => Ok(values.iter().copied().max().unwrap_or(0)),
+                OperatorType::LessThan => compare(u64::lt),
+                OperatorType::GreaterThan => compare(u64::gt),
+                OperatorType::EqualTo => compare(u64::eq),
+                OperatorType::Literal => unreachable!(),
             }
         }
     }
@@ -127,19 +133,19 @@ mod puzzle {
     impl Literal {
         #[cfg(test)]
         fn maybe_parse(input: &mut HexStringReader) -> Option<Literal> {
-            let version = input.read_bits(3);
-            let typ = input.read_bits(3);
+            let version = input.read_bits(3)?;
+            let typ = input.read_bits(3)?;
             if typ == 4 {
-                Some(Literal::parse(version, input))
+                Literal::parse(version, input)
             } else {
                 None
             }
         }
-        fn parse(version: u8, input: &mut HexStringReader) -> Literal {
+        fn parse(version: u8, input: &mut HexStringReader) -> Option<Literal> {
             let mut nybbles: Vec<u8> = Vec::new();
             loop {
-                let stop_flag = input.read_bits(1) == 0;
-                let next = input.read_bits(4);
+                let stop_flag = input.read_bits(1)? == 0;
+                let next = input.read_bits(4)?;
                 nybbles.push(next);
                 if stop_flag {
                     break;
@@ -151,10 +157,10 @@ mod puzzle {
                 value = value | (*nybble as u64) << shift;
                 shift = shift + 4;
             }
-            Literal {
+            Some(Literal {
                 version: version,
                 value: value,
-            }
+            })
         }
     }
 
@@ -194,50 +200,57 @@ mod puzzle {
     impl Operator {
         #[cfg(test)]
         fn maybe_parse(input: &mut HexStringReader) -> Option<Operator> {
-            let version = input.read_bits(3);
-            let typ: OperatorType = input.read_bits(3).try_into().ok()?;
+            let version = input.read_bits(3)?;
+            let typ: OperatorType = input.read_bits(3)?.try_into().ok()?;
             if typ != OperatorType::Literal {
-                Some(Operator::parse(version, typ, input))
+                Operator::parse(version, typ, input)
             } else {
                 None
             }
         }
-        fn parse(version: u8, type_id: OperatorType, input: &mut HexStringReader) -> Operator {
-            let length_type_id = input.read_bits(1);
+        fn parse(
+            version: u8,
+            type_id: OperatorType,
+            input: &mut HexStringReader,
+        ) -> Option<Operator> {
+            let length_type_id = input.read_bits(1)?;
             if length_type_id == 0 {
-                let bit_length = input.read_bits_u16(15);
-                let packets = Operator::sub_parse_by_bits(bit_length, input);
-                Operator {
+                let bit_length = input.read_bits_u16(15)?;
+                let packets = Operator::sub_parse_by_bits(bit_length, input)?;
+                Some(Operator {
                     version: version,
                     type_id: type_id,
                     sub_packets: packets,
-                }
+                })
             } else {
-                let packet_length = input.read_bits_u16(11);
-                let packets = Operator::sub_parse_by_packets(packet_length, input);
-                Operator {
+                let packet_length = input.read_bits_u16(11)?;
+                let packets = Operator::sub_parse_by_packets(packet_length, input)?;
+                Some(Operator {
                     version: version,
                     type_id: type_id,
                     sub_packets: packets,
-                }
+                })
             }
         }
 
-        fn sub_parse_by_bits(bit_length: u16, input: &mut HexStringReader) -> Vec<Packet> {
+        fn sub_parse_by_bits(bit_length: u16, input: &mut HexStringReader) -> Option<Vec<Packet>> {
             let mut packets: Vec<Packet> = Vec::new();
             let starting_offset = input.offset.clone();
             while input.offset - starting_offset < bit_length as usize {
-                packets.push(Packet::maybe_parse(input).unwrap())
+                packets.push(Packet::maybe_parse(input)?)
             }
-            packets
+            Some(packets)
         }
 
-        fn sub_parse_by_packets(packet_length: u16, input: &mut HexStringReader) -> Vec<Packet> {
+        fn sub_parse_by_packets(
+            packet_length: u16,
+            input: &mut HexStringReader,
+        ) -> Option<Vec<Packet>> {
             let mut packets: Vec<Packet> = Vec::with_capacity(packet_length as usize);
             for _ in 0..packet_length {
-                packets.push(Packet::maybe_parse(input).unwrap())
+                packets.push(Packet::maybe_parse(input)?)
             }
-            packets
+            Some(packets)
         }
     }
 
@@ -247,6 +260,9 @@ mod puzzle {
     }
     impl HexString {
         pub fn parse(input: &str) -> Option<HexString> {
+            if !input.is_ascii() {
+                return None;
+            }
             let mut bytes: Vec<u8> = Vec::with_capacity(input.len() / 2);
             for i in 0..input.len() / 2 {
                 let start = i * 2;
@@ -316,20 +332,56 @@ mod puzzle {
                 offset: 0,
             }
         }
-        fn read_bits(&mut self, len: usize) -> u8 {
+        /// Whether `len` more bits can be read, a truncated transmission
+        /// ends mid-packet
+        fn has_bits(&self, len: usize) -> bool {
+            self.offset + len <= self.hex_string.bytes.len() * 8
+        }
+
+        fn read_bits(&mut self, len: usize) -> Option<u8> {
+            if !self.has_bits(len) {
+                return None;
+            }
             let res = HexString::read_bits(&self.hex_string, self.offset, len);
             self.offset += len;
-            res
+            Some(res)
         }
 
-        fn read_bits_u16(&mut self, len: usize) -> u16 {
+        fn read_bits_u16(&mut self, len: usize) -> Option<u16> {
             if len > 16 {
                 panic!("len too large");
             }
+            if !self.has_bits(len) {
+                return None;
+            }
             let res = self.hex_string.read_bits_u16(self.offset, len);
             self.offset = self.offset + len;
-            res
+            Some(res)
+        }
+    }
+
+    pub fn parse_input(input: &str) -> Result<Packet, AocError> {
+        let (i, line) = input
+            .lines()
+            .enumerate()
+            .find(|(_, l)| !l.trim().is_empty())
+            .ok_or_else(|| AocError::new(DAY, "empty transmission"))?;
+        let indent = line.len() - line.trim_start().len();
+        let digits = line.trim();
+        if let Some((col, c)) = digits.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            return Err(AocError::at(
+                DAY,
+                i,
+                indent + col,
+                &c.to_string(),
+                "expected a hexadecimal digit",
+            ));
         }
+        let hex_string = HexString::parse(digits)
+            .ok_or_else(|| AocError::on_line(DAY, i, line, "expected hexadecimal digits"))?;
+        Packet::maybe_parse(&mut HexStringReader::new(hex_string)).ok_or_else(|| {
+            AocError::on_line(DAY, i, line, "transmission ends in the middle of a packet")
+        })
     }
 
     #[cfg(test)]
@@ -404,23 +456,23 @@ mod puzzle {
             reader.read_bits(3);
             reader.read_bits(3);
             reader.read_bits(1);
-            assert_eq!(reader.read_bits_u16(15), 27);
+            assert_eq!(reader.read_bits_u16(15), Some(27));
 
             let hex_string = HexString::parse("EE00D40C823060").unwrap();
             let mut reader = HexStringReader::new(hex_string);
             reader.read_bits(3);
             reader.read_bits(3);
             reader.read_bits(1);
-            assert_eq!(reader.read_bits_u16(11), 3);
+            assert_eq!(reader.read_bits_u16(11), Some(3));
 
             let hex_string = HexString::parse("1A8002FA78").unwrap();
             let mut reader = HexStringReader::new(hex_string.clone());
             reader.read_bits(4);
             reader.read_bits(3);
             reader.read_bits(3);
-            assert_eq!(reader.read_bits(1), 0);
+            assert_eq!(reader.read_bits(1), Some(0));
             assert_eq!(hex_string.read_bits_u16(11, 15), 11);
-            assert_eq!(reader.read_bits_u16(15), 11);
+            assert_eq!(reader.read_bits_u16(15), Some(11));
         }
 
         #[test]
@@ -435,54 +487,88 @@ mod puzzle {
             let hex_string = HexString::parse("8002F4").unwrap();
             assert_eq!(hex_string.read_bits(3 + 8, 15 - 8), 0xb);
         }
+
+        #[test]
+        fn test_parse_input_errors() {
+            let e = parse_input("\n  8A00Z\n").err().unwrap();
+            assert_eq!((e.line, e.column), (Some(2), Some(7)));
+            assert_eq!(e.text, "Z");
+
+            // an operator packet announcing more sub-packets than follow
+            let e = parse_input("8A004A80").err().unwrap();
+            assert_eq!(e.line, Some(1));
+            assert!(parse_input("8A004A801A8002F478").is_ok());
+        }
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let hex_string = puzzle::HexString::parse(input)?;
-    let mut reader = puzzle::HexStringReader::new(hex_string);
-    let packet = puzzle::Packet::maybe_parse(&mut reader)?;
-    Some(
-        packet
-            .vec()
-            .iter()
-            .fold(0u64, |acc, p| acc + p.version() as u64),
-    )
+use crate::solution::{Answer, Error, Result};
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let packet = puzzle::parse_input(input)?;
+    Ok(packet
+        .vec()
+        .iter()
+        .fold(0u64, |acc, p| acc + p.version() as u64)
+        .into())
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let root_packet = puzzle::parse_input(input)?;
+    Ok(root_packet.evaluate().map_err(Error::no_answer)?.into())
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let hex_string = puzzle::HexString::parse(input)?;
-    let mut reader = puzzle::HexStringReader::new(hex_string);
-    let root_packet = puzzle::Packet::maybe_parse(&mut reader)?;
-    Some(root_packet.evaluate())
+pub struct Day16;
+impl crate::solution::Solution for Day16 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![part_one, part_two]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| puzzle::parse_input(input).map(drop).map_err(Error::from))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLE_1: &'static str = "8A004A801A8002F478";
-    const EXAMPLE_2: &'static str = "620080001611562C8802118E34";
-    const EXAMPLE_3: &'static str = "C0015000016115A2E0802F182340";
-    const EXAMPLE_4: &'static str = "A0016C880162017C3686B18A3D4780";
-
     #[test]
     fn test_part_one_examples() {
-        assert_eq!(part_one(EXAMPLE_1), Some(16));
-        assert_eq!(part_one(EXAMPLE_2), Some(12));
-        assert_eq!(part_one(EXAMPLE_3), Some(23));
-        assert_eq!(part_one(EXAMPLE_4), Some(31));
+        assert_eq!(part_one(EXAMPLES[0].input), Ok(Answer::Integer(16)));
+        assert_eq!(part_one(EXAMPLES[1].input), Ok(Answer::Integer(12)));
+        assert_eq!(part_one(EXAMPLES[2].input), Ok(Answer::Integer(23)));
+        assert_eq!(part_one(EXAMPLES[3].input), Ok(Answer::Integer(31)));
     }
 
     #[test]
     fn test_part_two_examples() {
-        assert_eq!(part_two("C200B40A82"), Some(3)); //sum 1+2
-        assert_eq!(part_two("04005AC33890"), Some(54)); //6*9 (nice)
-        assert_eq!(part_two("880086C3E88112"), Some(7)); //min(7,8,9)
-        assert_eq!(part_two("CE00C43D881120"), Some(9)); //max(7,8,9)
-        assert_eq!(part_two("D8005AC2A8F0"), Some(1)); //5 < 15 == 1
-        assert_eq!(part_two("F600BC2D8F"), Some(0)); //5 > 15 == 0
-        assert_eq!(part_two("9C005AC2F8F0"), Some(0)); //(5 == 15) == 0
-        assert_eq!(part_two("9C0141080250320F1802104A08"), Some(1)); // {1+3 = 2*2} = 1
+        assert_eq!(part_two(EXAMPLES[4].input), Ok(Answer::Integer(3))); //sum 1+2
+        assert_eq!(part_two(EXAMPLES[5].input), Ok(Answer::Integer(54))); //6*9 (nice)
+        assert_eq!(part_two(EXAMPLES[6].input), Ok(Answer::Integer(7))); //min(7,8,9)
+        assert_eq!(part_two(EXAMPLES[7].input), Ok(Answer::Integer(9))); //max(7,8,9)
+        assert_eq!(part_two(EXAMPLES[8].input), Ok(Answer::Integer(1))); //5 < 15 == 1
+        assert_eq!(part_two(EXAMPLES[9].input), Ok(Answer::Integer(0))); //5 > 15 == 0
+        assert_eq!(part_two(EXAMPLES[10].input), Ok(Answer::Integer(0))); //(5 == 15) == 0
+        assert_eq!(part_two(EXAMPLES[11].input), Ok(Answer::Integer(1))); // {1+3 = 2*2} = 1
+    }
+
+    #[test]
+    fn test_comparison_without_two_operands() {
+        // a less-than packet holding only the literal 5
+        assert!(matches!(part_two("1A004428"), Err(Error::NoAnswer(_))));
+        assert_eq!(part_one("1A004428"), Ok(Answer::Integer(0)));
     }
 }
@@ -1,4 +1,17 @@
+const DAY: u32 = 17;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "target area: x=20..30, y=-10..-5",
+    answers: &[Some("45"), Some("112")],
+}];
+
 mod puzzle {
+    use super::DAY;
+    use crate::util::error::AocError;
+
     #[derive(Debug, PartialEq, Clone)]
     pub struct Vec2 {
         pub x: i32,
@@ -181,12 +194,16 @@ mod puzzle {
         }
     }
 
-    pub fn parse_input(input: &str) -> Option<Target> {
-        parser::input_parser(input).ok().map(|t| t.1)
+    pub fn parse_input(input: &str) -> Result<Target, AocError> {
+        parser::input_parser(input).map(|t| t.1).map_err(|e| {
+            AocError::from_nom(DAY, input, e, "expected `target area: x=A..B, y=C..D`")
+        })
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
+use crate::solution::{Answer, Error, Result};
+
+pub fn part_one(input: &str) -> Result<Answer> {
     let target = puzzle::parse_input(input)?;
     let mut max_height: u64 = 0;
     for x in 1..(target.top_right.x) {
@@ -194,15 +211,15 @@ pub fn part_one(input: &str) -> Option<u64> {
             let velocity = puzzle::Vec2 { x: x, y: y };
             let mut probe = puzzle::Probe::with_velocity(velocity.clone());
             if let Some(height) = probe.max_height(&target) {
-                println!("{:?} -> {}", velocity, height);
+                crate::trace!("{:?} -> {}", velocity, height);
                 max_height = std::cmp::max(max_height, height);
             }
         }
     }
-    Some(max_height)
+    Ok(max_height.into())
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &str) -> Result<Answer> {
     let target = puzzle::parse_input(input)?;
     let mut count: u64 = 0;
     for x in 1..(target.top_right.x + 10) {
@@ -214,7 +231,30 @@ pub fn part_two(input: &str) -> Option<u64> {
             }
         }
     }
-    Some(count)
+    Ok(count.into())
+}
+
+pub struct Day17;
+impl crate::solution::Solution for Day17 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![part_one, part_two]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| puzzle::parse_input(input).map(drop).map_err(Error::from))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 #[cfg(test)]
@@ -222,11 +262,11 @@ mod tests {
     use super::*;
     #[test]
     fn test_part_one_example() {
-        assert_eq!(part_one("target area: x=20..30, y=-10..-5"), Some(45));
+        assert_eq!(part_one(EXAMPLES[0].input), Ok(Answer::Integer(45)));
     }
 
     #[test]
     fn test_part_two_example() {
-        assert_eq!(part_two("target area: x=20..30, y=-10..-5"), Some(112));
+        assert_eq!(part_two(EXAMPLES[0].input), Ok(Answer::Integer(112)));
     }
 }
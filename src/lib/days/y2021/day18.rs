@@ -1,4 +1,75 @@
+const DAY: u32 = 18;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: "\
+[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]
+",
+        answers: &[Some("4140"), Some("3993")],
+    },
+    Example {
+        input: "\
+[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
+[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
+[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
+[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
+[7,[5,[[3,8],[1,4]]]]
+[[2,[2,2]],[8,[8,1]]]
+[2,9]
+[1,[[[9,3],9],[[9,0],[0,7]]]]
+[[[5,[7,4]],7],1]
+[[[[4,2],2],6],[8,7]]
+",
+        answers: &[Some("3488"), None],
+    },
+    Example {
+        input: "\
+[1,1]
+[2,2]
+[3,3]
+[4,4]
+",
+        answers: &[Some("445"), None],
+    },
+    Example {
+        input: "\
+[1,1]
+[2,2]
+[3,3]
+[4,4]
+[5,5]
+",
+        answers: &[Some("791"), None],
+    },
+    Example {
+        input: "\
+[1,1]
+[2,2]
+[3,3]
+[4,4]
+[5,5]
+[6,6]
+",
+        answers: &[Some("1137"), None],
+    },
+];
+
 mod puzzle {
+    use super::DAY;
+    use crate::util::error::AocError;
+
     #[derive(PartialEq, Debug, Clone)]
     pub struct Elem {
         depth: u8,
@@ -9,6 +80,7 @@ mod puzzle {
         nums: Vec<Elem>,
     }
     impl SnailfishNum {
+        #[cfg(test)]
         pub fn parse(input: &str) -> Option<SnailfishNum> {
             parser::parse(input).ok().map(|t| t.1)
         }
@@ -107,7 +179,7 @@ mod puzzle {
         use nom::{
             branch::alt,
             character::complete::char,
-            combinator::{map, value},
+            combinator::{fail, map, value},
             IResult,
         };
 
@@ -129,22 +201,54 @@ mod puzzle {
                     value(Action::NoOp, char(',')),
                     map(nom::character::complete::u8, |v| Action::Push(v)),
                 ))(input_c)?;
-                input_c = n;
 
                 match action {
                     Action::Inc => d += 1,
+                    Action::Dec if d == 0 => return fail(input_c),
                     Action::Dec => d -= 1,
                     Action::Push(v) => elems.push(Elem { depth: d, value: v }),
                     Action::NoOp => (),
                 }
+                input_c = n;
+            }
+            if d != 0 {
+                return fail(input_c);
             }
             Ok(("", SnailfishNum { nums: elems }))
         }
     }
 
+    pub fn parse_input(input: &str) -> Result<Vec<SnailfishNum>, AocError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty())
+            .map(|(i, l)| {
+                // located within the whole line so the column counts its indent
+                let (_, num) = parser::parse(l.trim()).map_err(|e| {
+                    AocError::from_nom(DAY, l.trim_end(), e, "expected a snailfish number")
+                        .offset_by_lines(i)
+                })?;
+                Ok(num)
+            })
+            .collect()
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
+        use crate::util::prop::{self, Gen, Rng};
+
+        #[test]
+        fn test_parse_input_errors() {
+            let e = parse_input("[1,2]\n  [[1,2],x]").err().unwrap();
+            assert_eq!((e.line, e.column), (Some(2), Some(10)));
+            assert_eq!(e.text, "x]");
+
+            let e = parse_input("[1,2]]").err().unwrap();
+            assert_eq!((e.line, e.column), (Some(1), Some(6)));
+            assert!(parse_input("[[1,2]").is_err());
+        }
 
         #[test]
         fn test_num_magnitude() {
@@ -246,33 +350,121 @@ mod puzzle {
                 SnailfishNum::parse("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap()
             )
         }
+
+        /// A snailfish number in its nested form, for generating inputs
+        #[derive(Clone, Debug)]
+        enum Tree {
+            Regular(u8),
+            Pair(Box<Tree>, Box<Tree>),
+        }
+        impl Tree {
+            /// A reduced number: no pair is nested inside four others and
+            /// every regular number is below 10
+            fn generate(rng: &mut Rng) -> Tree {
+                fn element(rng: &mut Rng, depth: u8) -> Tree {
+                    if depth < 4 && rng.bool() {
+                        Tree::Pair(
+                            Box::new(element(rng, depth + 1)),
+                            Box::new(element(rng, depth + 1)),
+                        )
+                    } else {
+                        Tree::Regular(rng.range(0..=9) as u8)
+                    }
+                }
+                Tree::Pair(Box::new(element(rng, 1)), Box::new(element(rng, 1)))
+            }
+
+            /// Smaller trees that are still reduced: pairs replaced by one
+            /// of their halves and regular numbers made smaller
+            fn shrink(&self) -> Vec<Tree> {
+                match self {
+                    Tree::Regular(0) => vec![],
+                    Tree::Regular(n) => vec![Tree::Regular(0), Tree::Regular(n - 1)],
+                    Tree::Pair(l, r) => {
+                        let mut smaller = vec![(**l).clone(), (**r).clone()];
+                        for l in l.shrink() {
+                            smaller.push(Tree::Pair(Box::new(l), r.clone()));
+                        }
+                        for r in r.shrink() {
+                            smaller.push(Tree::Pair(l.clone(), Box::new(r)));
+                        }
+                        smaller
+                    }
+                }
+            }
+
+            fn to_num(&self) -> SnailfishNum {
+                SnailfishNum::parse(&self.to_string()).unwrap()
+            }
+        }
+        impl std::fmt::Display for Tree {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    Tree::Regular(n) => write!(f, "{}", n),
+                    Tree::Pair(l, r) => write!(f, "[{},{}]", l, r),
+                }
+            }
+        }
+
+        fn trees() -> impl Gen<Value = Tree> {
+            prop::from_fn(Tree::generate, |tree: &Tree| {
+                let mut smaller = tree.shrink();
+                smaller.retain(|t| matches!(t, Tree::Pair(..)));
+                smaller
+            })
+        }
+
+        fn is_reduced(num: &SnailfishNum) -> bool {
+            num.nums
+                .iter()
+                .all(|e| (1..=4).contains(&e.depth) && e.value < 10)
+                && !num.clone().explode()
+                && !num.clone().split()
+        }
+
+        /// Whether the depths describe a binary tree, where every pair has
+        /// exactly two elements
+        fn is_tree(num: &SnailfishNum) -> bool {
+            num.nums.iter().map(|e| 32 >> e.depth).sum::<u32>() == 32
+        }
+
+        #[test]
+        fn test_prop_parse_reduced() {
+            prop::check(&trees(), |tree| {
+                let num = tree.to_num();
+                is_reduced(&num) && is_tree(&num)
+            });
+        }
+
+        #[test]
+        fn test_prop_add_and_reduce() {
+            prop::check(&prop::zip(trees(), trees()), |(a, b)| {
+                let mut sum = a.to_num();
+                sum.add_and_reduce(&mut b.to_num());
+                is_reduced(&sum) && is_tree(&sum)
+            });
+        }
     }
 }
-pub fn part_one(input: &str) -> Option<u64> {
-    let mut nums = input
-        .lines()
-        .map(|l| l.trim())
-        .filter(|l| !l.is_empty())
-        .map(|l| puzzle::SnailfishNum::parse(l))
-        .collect::<Option<Vec<puzzle::SnailfishNum>>>()?;
+
+use crate::solution::{Answer, Error, Result};
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let mut nums = puzzle::parse_input(input)?;
 
     nums.iter_mut()
         .reduce(|a, b| {
             a.add_and_reduce(b);
             a
         })
-        .map(|n| n.magnitude())
+        .map(|n| n.magnitude().into())
+        .ok_or_else(|| Error::no_answer("no numbers to add"))
 }
 
 use itertools::Itertools;
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let nums = input
-        .lines()
-        .map(|l| l.trim())
-        .filter(|l| !l.is_empty())
-        .map(|l| puzzle::SnailfishNum::parse(l))
-        .collect::<Option<Vec<puzzle::SnailfishNum>>>()?;
+pub fn part_two(input: &str) -> Result<Answer> {
+    let nums = puzzle::parse_input(input)?;
 
     nums.iter()
         .cartesian_product(nums.iter())
@@ -285,56 +477,52 @@ pub fn part_two(input: &str) -> Option<u64> {
         })
         .map(|n| n.magnitude())
         .max()
+        .map(Answer::from)
+        .ok_or_else(|| Error::no_answer("no numbers to add"))
+}
+
+pub struct Day18;
+impl crate::solution::Solution for Day18 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![part_one, part_two]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| puzzle::parse_input(input).map(drop).map_err(Error::from))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLE: &'static str = r#"
-    [[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-    [[[5,[2,8]],4],[5,[[9,9],0]]]
-    [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-    [[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-    [[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-    [[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-    [[[[5,4],[7,7]],8],[[8,3],8]]
-    [[9,3],[[9,9],[6,[4,9]]]]
-    [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-    [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]
-  "#;
-
-    const EXAMPLE_2: &'static str = r#"
-    [[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
-    [7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
-    [[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
-    [[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
-    [7,[5,[[3,8],[1,4]]]]
-    [[2,[2,2]],[8,[8,1]]]
-    [2,9]
-    [1,[[[9,3],9],[[9,0],[0,7]]]]
-    [[[5,[7,4]],7],1]
-    [[[[4,2],2],6],[8,7]] 
-  "#;
-
     #[test]
     fn test_part_one_example() {
-        assert_eq!(part_one("[1,1]\n[2,2]\n[3,3]\n[4,4]"), Some(445));
-        assert_eq!(part_one("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]"), Some(791));
-        assert_eq!(
-            part_one("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]\n[6,6]"),
-            Some(1137)
-        );
-        assert_eq!(part_one(EXAMPLE_2), Some(3488));
-        assert_eq!(part_one(EXAMPLE), Some(4140));
+        assert_eq!(part_one(EXAMPLES[2].input), Ok(Answer::Integer(445)));
+        assert_eq!(part_one(EXAMPLES[3].input), Ok(Answer::Integer(791)));
+        assert_eq!(part_one(EXAMPLES[4].input), Ok(Answer::Integer(1137)));
+        assert_eq!(part_one(EXAMPLES[1].input), Ok(Answer::Integer(3488)));
+        assert_eq!(part_one(EXAMPLES[0].input), Ok(Answer::Integer(4140)));
     }
 
     #[test]
     fn test_part_two_example() {
-        //assert_eq!(part_one("[1,1]\n[2,2]\n[3,3]\n[4,4]"), Some(445));
-        //assert_eq!(part_one("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]"), Some(791));
-        //assert_eq!(part_one("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]\n[6,6]"), Some(1137));
-        //assert_eq!(part_one(EXAMPLE_2), Some(3488));
-        assert_eq!(part_two(EXAMPLE), Some(3993));
+        //assert_eq!(part_one("[1,1]\n[2,2]\n[3,3]\n[4,4]"), Ok(Answer::Integer(445)));
+        //assert_eq!(part_one("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]"), Ok(Answer::Integer(791)));
+        //assert_eq!(part_one("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]\n[6,6]"), Ok(Answer::Integer(1137)));
+        //assert_eq!(part_one(EXAMPLES[1].input), Ok(Answer::Integer(3488)));
+        assert_eq!(part_two(EXAMPLES[0].input), Ok(Answer::Integer(3993)));
     }
 }
@@ -1,3 +1,13 @@
+const DAY: u32 = 19;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[Example {
+    input: include_str!("../../../../inputs/example/2021/day19.txt"),
+    answers: &[Some("79"), Some("3621")],
+}];
+
 mod puzzle {
     use itertools::Itertools;
     use nalgebra as na;
@@ -7,6 +17,8 @@ mod puzzle {
     };
     use std::collections::HashSet;
 
+    use super::DAY;
+    use crate::util::error::AocError;
     use crate::util::intersect::count_intersect;
 
     type Vec3f = na::Vector3<f32>;
@@ -269,8 +281,15 @@ mod puzzle {
             beacons: beacons,
         }
     }
-    pub fn parse(input: &str) -> Option<Vec<ScannerInput>> {
-        parser::puzzle_input(input).ok().map(|t| t.1)
+    pub fn parse_input(input: &str) -> Result<Vec<ScannerInput>, AocError> {
+        let expected = "expected `--- scanner N ---` followed by `x,y,z` beacon positions";
+        let (rest, scanners) =
+            parser::puzzle_input(input).map_err(|e| AocError::from_nom(DAY, input, e, expected))?;
+        if !rest.trim().is_empty() {
+            let offset = input.len() - rest.trim_start().len();
+            return Err(AocError::at_offset(DAY, input, offset, expected));
+        }
+        Ok(scanners)
     }
     mod parser {
         use super::*;
@@ -330,6 +349,15 @@ mod puzzle {
     mod tests {
         use super::*;
 
+        #[test]
+        fn test_parse_input_errors() {
+            let input = "--- scanner 0 ---\n1,2,3\n4,5\n";
+            let e = parse_input(input).err().unwrap();
+            assert_eq!((e.line, e.column), (Some(3), Some(1)));
+            assert_eq!(e.text, "4,5");
+            assert!(parse_input("").is_err());
+        }
+
         #[test]
         fn test_likely_pairs() {
             let pairs = likely_pairs(&parser::puzzle_input(EXAMPLE).unwrap().1);
@@ -344,7 +372,7 @@ mod puzzle {
 
         #[test]
         fn test_align_pair() {
-            let input = parse(EXAMPLE).unwrap();
+            let input = parse_input(EXAMPLE).unwrap();
             let isometry = align_pair(
                 &input[0].beacon_relative_locations,
                 &input[1].beacon_relative_locations,
@@ -364,7 +392,7 @@ mod puzzle {
         #[test]
         fn test_example_scanner_positions_manual_path() {
             fn test_align(a: usize, b: usize) -> Isometry3f {
-                let input = parse(EXAMPLE).unwrap();
+                let input = parse_input(EXAMPLE).unwrap();
                 align_pair(
                     &input[a].beacon_relative_locations,
                     &input[b].beacon_relative_locations,
@@ -397,7 +425,7 @@ mod puzzle {
 
         #[test]
         fn test_example_scanner_positions_manual_path_from_graph() {
-            let input = parse(EXAMPLE).unwrap();
+            let input = parse_input(EXAMPLE).unwrap();
 
             let pairs = likely_pairs(&input);
             let graph = scanner_graph(pairs, &input);
@@ -427,7 +455,7 @@ mod puzzle {
 
         #[test]
         fn test_example_map_counts() {
-            let input = parse(EXAMPLE).unwrap();
+            let input = parse_input(EXAMPLE).unwrap();
             let map = build_map(&input);
 
             let scanners = HashedPoint3::set_from_slice(&vec![
@@ -443,7 +471,7 @@ mod puzzle {
 
         #[test]
         fn test_corresponding_points() {
-            let input = parse(EXAMPLE).unwrap();
+            let input = parse_input(EXAMPLE).unwrap();
             let pairs = corresponding_points(
                 &input[0].beacon_relative_locations,
                 &input[1].beacon_relative_locations,
@@ -604,17 +632,19 @@ mod puzzle {
     }
 
     #[cfg(test)]
-    pub const EXAMPLE: &'static str = include_str!("examples/day19-full.txt");
+    pub const EXAMPLE: &str = super::EXAMPLES[0].input;
 }
 
+use crate::solution::{Answer, Error, Result};
 use itertools::Itertools;
 
-pub fn part_one(input: &str) -> Option<u64> {
-    puzzle::parse(input).map(|scanner_input| puzzle::build_map(&scanner_input).beacons.len() as u64)
+pub fn part_one(input: &str) -> Result<Answer> {
+    let scanner_input = puzzle::parse_input(input)?;
+    Ok((puzzle::build_map(&scanner_input).beacons.len() as u64).into())
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let scanner_input = puzzle::parse(input)?;
+pub fn part_two(input: &str) -> Result<Answer> {
+    let scanner_input = puzzle::parse_input(input)?;
     let map = puzzle::build_map(&scanner_input);
 
     map.scanners
@@ -622,6 +652,31 @@ pub fn part_two(input: &str) -> Option<u64> {
         .tuple_combinations()
         .map(|(a, b)| (i32::abs(a.x - b.x) + i32::abs(a.y - b.y) + i32::abs(a.z - b.z)) as u64)
         .max()
+        .map(Answer::from)
+        .ok_or_else(|| Error::no_answer("fewer than two scanners"))
+}
+
+pub struct Day19;
+impl crate::solution::Solution for Day19 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![part_one, part_two]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| puzzle::parse_input(input).map(drop).map_err(Error::from))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 #[cfg(test)]
@@ -630,11 +685,11 @@ mod tests {
 
     #[test]
     fn test_part_one_example() {
-        assert_eq!(part_one(puzzle::EXAMPLE), Some(79));
+        assert_eq!(part_one(puzzle::EXAMPLE), Ok(Answer::Integer(79)));
     }
 
     #[test]
     fn test_part_two_example() {
-        assert_eq!(part_two(puzzle::EXAMPLE), Some(3621));
+        assert_eq!(part_two(puzzle::EXAMPLE), Ok(Answer::Integer(3621)));
     }
 }
@@ -0,0 +1,254 @@
+const DAY: u32 = 20;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[Example {
+    input: include_str!("../../../../inputs/example/2021/day20.txt"),
+    answers: &[Some("35"), Some("3351")],
+}];
+
+mod puzzle {
+    use super::DAY;
+    use crate::util::error::AocError;
+    use crate::util::grid::{Grid, Neighborhood};
+    use crate::util::pos::Pos;
+    use crate::util::sparse_grid::SparseGrid;
+    use std::fmt::Display;
+    // build some kind of Grid<T> data structure, maybe back it by an nalgebra DMatrix under the hood.
+    // Grid<T>::neighbors() -> Vec<(usize, usize)> giving us the 9 neighbors
+    // index into the "image enhancement string" based on
+
+    // since output is "infinite" we need to compute a larger area than input size
+    // -3 to n + 3 (I think)
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum PixelValue {
+        Dark,
+        Light,
+    }
+    impl PixelValue {
+        pub fn is_lit(&self) -> bool {
+            match self {
+                PixelValue::Light => true,
+                _ => false,
+            }
+        }
+
+        pub fn parse(c: char) -> Option<PixelValue> {
+            match c {
+                '#' => Some(PixelValue::Light),
+                '.' => Some(PixelValue::Dark),
+                _ => None,
+            }
+        }
+
+        #[cfg(test)]
+        pub fn parse_pattern(s: &str) -> Option<Vec<PixelValue>> {
+            s.chars()
+                .map(|c| PixelValue::parse(c))
+                .collect::<Option<Vec<PixelValue>>>()
+        }
+    }
+    impl Display for PixelValue {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+            match self {
+                PixelValue::Dark => write!(f, "."),
+                PixelValue::Light => write!(f, "#"),
+            }
+        }
+    }
+
+    /// Pixels of one line, or an error pointing at the first bad character
+    fn parse_line(i: usize, line: &str) -> Result<Vec<PixelValue>, AocError> {
+        let indent = line.len() - line.trim_start().len();
+        line.trim()
+            .chars()
+            .enumerate()
+            .map(|(col, c)| {
+                PixelValue::parse(c).ok_or_else(|| {
+                    AocError::at(DAY, i, indent + col, &c.to_string(), "expected `#` or `.`")
+                })
+            })
+            .collect()
+    }
+
+    pub fn parse_input(input: &str) -> Result<(Vec<PixelValue>, Grid<PixelValue>), AocError> {
+        let (i, algorithm) = input
+            .lines()
+            .enumerate()
+            .find(|(_, l)| !l.trim().is_empty())
+            .ok_or_else(|| AocError::new(DAY, "missing image enhancement algorithm"))?;
+        let enhancement = parse_line(i, algorithm)?;
+        if enhancement.len() != 512 {
+            return Err(AocError::on_line(
+                DAY,
+                i,
+                algorithm,
+                "expected an enhancement algorithm of 512 pixels",
+            ));
+        }
+        let image_start: usize = input.split_inclusive('\n').take(i + 1).map(str::len).sum();
+        let image = &input[image_start..];
+        if image.trim().is_empty() {
+            return Err(AocError::new(DAY, "missing input image"));
+        }
+        let grid = Grid::parse_with(DAY, image, |c| {
+            PixelValue::parse(c).ok_or("expected `#` or `.`")
+        })
+        .map_err(|e| e.offset_by_lines(i + 1))?;
+
+        Ok((enhancement, grid))
+    }
+
+    pub fn pixel_pattern_to_index<'a>(pattern: impl IntoIterator<Item = &'a PixelValue>) -> usize {
+        pattern.into_iter().fold(0, |index, p| {
+            (index << 1) | (*p == PixelValue::Light) as usize
+        })
+    }
+
+    /// The 3x3 pixels centered on (row, col), top-left to bottom-right
+    pub fn pixel_pattern_at<'a>(
+        image: &'a Grid<PixelValue>,
+        default: &'a PixelValue,
+        row: i32,
+        col: i32,
+    ) -> impl Iterator<Item = &'a PixelValue> {
+        image.neighbor_values(row, col, Neighborhood::MooreWithSelf, default)
+    }
+
+    /// How far the image grows on each side per enhancement
+    const GROWTH: usize = 1;
+    pub fn apply_enhancement(
+        enhancement: &[PixelValue],
+        image: Grid<PixelValue>,
+        oob_value: &PixelValue,
+    ) -> Grid<PixelValue> {
+        let image = image.pad(GROWTH, oob_value.clone());
+        Grid::from_fn(image.width(), image.height(), |row, col| {
+            let pattern = pixel_pattern_at(&image, oob_value, row as i32, col as i32);
+            enhancement[pixel_pattern_to_index(pattern)].clone()
+        })
+    }
+
+    /// What the pixels far from anything lit, which all see the same
+    /// pattern, become after an enhancement
+    fn next_background(enhancement: &[PixelValue], background: &PixelValue) -> PixelValue {
+        enhancement[pixel_pattern_to_index(std::iter::repeat_n(background, 9))].clone()
+    }
+
+    /// The infinite image after `steps` enhancements. The steps run on a
+    /// dense grid grown around the input, with the background outside it
+    /// tracked alongside; only the result becomes a SparseGrid. Enhancing a
+    /// SparseGrid on each step instead is about 4x slower.
+    pub fn repeatedly_enhance(
+        enhancement: &[PixelValue],
+        image: Grid<PixelValue>,
+        steps: usize,
+    ) -> SparseGrid<PixelValue> {
+        let mut image = image;
+        let mut background = PixelValue::Dark;
+        for _ in 0..steps {
+            image = apply_enhancement(enhancement, image, &background);
+            background = next_background(enhancement, &background);
+        }
+        let growth = (steps * GROWTH) as i32;
+        SparseGrid::from_grid(&image, Pos::new(-growth, -growth), background)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_pixel_pattern_to_index() {
+            let pattern = &PixelValue::parse_pattern("...#...#.").unwrap();
+            assert_eq!(pixel_pattern_to_index(pattern), 0b000100010);
+        }
+    }
+}
+
+use crate::solution::{Answer, Error, Result};
+use crate::util::sparse_grid::SparseGrid;
+
+/// Lit pixels in the image, which are endless if the background is lit
+fn lit_pixels(image: &SparseGrid<puzzle::PixelValue>) -> Result<Answer> {
+    if image.background().is_lit() {
+        return Err(Error::no_answer("infinitely many pixels are lit"));
+    }
+    Ok((image.len() as u64).into())
+}
+
+pub fn part_one(input: &str) -> Result<Answer> {
+    let (enhancement, image) = puzzle::parse_input(input)?;
+    let output = puzzle::repeatedly_enhance(&enhancement, image, 2);
+    lit_pixels(&output)
+}
+
+pub fn part_two(input: &str) -> Result<Answer> {
+    let (enhancement, image) = puzzle::parse_input(input)?;
+
+    let output = puzzle::repeatedly_enhance(&enhancement, image, 50);
+
+    crate::debug!("out:\n{}", output);
+    lit_pixels(&output)
+}
+
+pub struct Day20;
+impl crate::solution::Solution for Day20 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![part_one, part_two]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| puzzle::parse_input(input).map(drop).map_err(Error::from))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_example() {
+        assert_eq!(part_one(EXAMPLES[0].input), Ok(Answer::Integer(35)));
+    }
+
+    #[test]
+    fn test_part_two_example() {
+        assert_eq!(part_two(EXAMPLES[0].input), Ok(Answer::Integer(3351)));
+    }
+
+    #[test]
+    fn test_background_that_stays_lit() {
+        let input = format!("{}\n\n.\n", "#".repeat(512));
+        assert!(part_one(&input).is_err());
+        let input = format!("{}.\n\n.\n", "#".repeat(511));
+        assert_eq!(part_one(&input), Ok(Answer::Integer(0)));
+    }
+
+    #[test]
+    fn test_image_errors_count_lines_from_the_top() {
+        let algorithm = ".".repeat(512);
+        let e = puzzle::parse_input(&format!("{}\n\n#.\n.x\n", algorithm)).unwrap_err();
+        assert_eq!((e.line, e.column), (Some(4), Some(2)));
+        let e = puzzle::parse_input(&format!("\n{}\n\n#.\n.#.\n", algorithm)).unwrap_err();
+        assert_eq!(
+            (e.line, e.message.as_str()),
+            (Some(5), "rows differ in length")
+        );
+        let e = puzzle::parse_input(&format!("{}\n\n", algorithm)).unwrap_err();
+        assert_eq!(e.message, "missing input image");
+    }
+}
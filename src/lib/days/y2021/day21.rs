@@ -1,6 +1,21 @@
 use std::collections::HashMap;
 
+const DAY: u32 = 21;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "\
+Player 1 starting position: 4
+Player 2 starting position: 8
+",
+    answers: &[Some("739785"), Some("444356092776315")],
+}];
+
 mod puzzle {
+    use super::DAY;
+    use crate::util::error::AocError;
 
     pub struct GameState {
         pub player_one: GamePlayer,
@@ -41,17 +56,17 @@ mod puzzle {
             let r2 = self.roll_die();
             let r3 = self.roll_die();
 
-            print!(
-                "{}",
-                match self.next_roller {
-                    PlayerId::PlayerOne => "P1: ",
-                    PlayerId::PlayerTwo => "P2: ",
-                }
+            let player = match self.next_roller {
+                PlayerId::PlayerOne => &mut self.player_one,
+                PlayerId::PlayerTwo => &mut self.player_two,
+            };
+            player.move_player(r1 + r2 + r3);
+            crate::trace!(
+                "{:?} rolls {}: {:?}",
+                self.next_roller,
+                r1 + r2 + r3,
+                player
             );
-            match self.next_roller {
-                PlayerId::PlayerOne => self.player_one.move_player(r1 + r2 + r3),
-                PlayerId::PlayerTwo => self.player_two.move_player(r1 + r2 + r3),
-            }
 
             self.next_roller = match self.next_roller {
                 PlayerId::PlayerOne => PlayerId::PlayerTwo,
@@ -80,11 +95,9 @@ mod puzzle {
     }
 
     pub mod parser {
-        use super::*;
         use crate::util::nom_helpers::ws;
         use nom::{
             bytes::complete::{tag, take_until},
-            combinator::map,
             sequence::{preceded, tuple},
             IResult,
         };
@@ -100,26 +113,45 @@ mod puzzle {
         pub fn player_positions(input: &str) -> IResult<&str, (u32, u32)> {
             tuple((ws(starting_position), ws(starting_position)))(input)
         }
+    }
 
-        // creates game state
-        pub fn puzzle_input(input: &str) -> IResult<&str, GameState> {
-            map(player_positions, |(player_one, player_two)| {
-                GameState::new(player_one, player_two)
-            })(input)
+    /// 1-indexed starting positions of both players
+    pub fn parse_positions(input: &str) -> Result<(u32, u32), AocError> {
+        let (_, (one, two)) = parser::player_positions(input).map_err(|e| {
+            AocError::from_nom(DAY, input, e, "expected `Player N starting position: P`")
+        })?;
+        for (player, position) in [one, two].iter().enumerate() {
+            if !(1..=10).contains(position) {
+                let (i, line) = input
+                    .lines()
+                    .enumerate()
+                    .filter(|(_, l)| !l.trim().is_empty())
+                    .nth(player)
+                    .unwrap_or((0, ""));
+                return Err(AocError::on_line(
+                    DAY,
+                    i,
+                    line,
+                    "expected a position from 1 to 10",
+                ));
+            }
         }
+        Ok((one, two))
     }
 
-    pub fn parse_input(input: &str) -> Option<GameState> {
-        let (_, game) = parser::puzzle_input(input).ok()?;
-        Some(game)
+    pub fn parse_input(input: &str) -> Result<GameState, AocError> {
+        let (one, two) = parse_positions(input)?;
+        Ok(GameState::new(one, two))
     }
 }
 
+use crate::solution::{Answer, Error, Result};
+
 // Board game simulation with "deterministic dice"
 // Each turn takes 3 "rolls" of sequence (1.100)
 // sum of rolls gives offset, wrapped into 1 to 10 which gives score for turn
 // play until score >= 100
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &str) -> Result<Answer> {
     let mut game = puzzle::parse_input(input)?;
 
     while game.take_turn() {}
@@ -130,9 +162,9 @@ pub fn part_one(input: &str) -> Option<u64> {
         puzzle::PlayerId::PlayerTwo => game.player_two.score,
     };
 
-    println!("End of game {:?} / {:?}", game.player_one, game.player_two);
-    println!("Losing {} rolls {}", losing_score, game.die_rolls);
-    Some((losing_score * game.die_rolls) as u64)
+    crate::debug!("End of game {:?} / {:?}", game.player_one, game.player_two);
+    crate::debug!("Losing {} rolls {}", losing_score, game.die_rolls);
+    Ok(((losing_score * game.die_rolls) as u64).into())
 }
 
 // Same board game but now with a "non-deterministic die". Each die roll creates different universes to evaluate.
@@ -265,18 +297,38 @@ mod part_two {
     }
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (_, (one, two)) = puzzle::parser::player_positions(input).ok()?;
+pub fn part_two(input: &str) -> Result<Answer> {
+    let (one, two) = puzzle::parse_positions(input)?;
     let game_state = part_two::GameState::new(one, two);
     let mut memo = HashMap::new();
     let output = part_two::part_two_puzzle(&game_state, &mut memo);
 
-    println!("Found solution, with {} unique states explored", memo.len());
+    crate::debug!("Found solution, with {} unique states explored", memo.len());
 
-    Some(std::cmp::max(
-        output.player_one_wins,
-        output.player_two_wins,
-    ))
+    Ok(std::cmp::max(output.player_one_wins, output.player_two_wins).into())
+}
+
+pub struct Day21;
+impl crate::solution::Solution for Day21 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![part_one, part_two]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| puzzle::parse_input(input).map(drop).map_err(Error::from))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 #[cfg(test)]
@@ -285,30 +337,21 @@ mod tests {
 
     #[test]
     fn test_part_one_example() {
-        let example = r#"
-    Player 1 starting position: 4
-    Player 2 starting position: 8"#;
-
-        assert_eq!(part_one(example), Some(739785));
+        assert_eq!(part_one(EXAMPLES[0].input), Ok(Answer::Integer(739785)));
     }
 
     #[test]
     fn test_part_one_example_parser() {
-        let example = r#"
-    Player 1 starting position: 4
-    Player 2 starting position: 8"#;
-
-        let (_, (one, two)) = puzzle::parser::player_positions(example).unwrap();
+        let (_, (one, two)) = puzzle::parser::player_positions(EXAMPLES[0].input).unwrap();
         assert_eq!(one, 4);
         assert_eq!(two, 8);
     }
 
     #[test]
     fn test_part_two_example() {
-        let example = r#"
-    Player 1 starting position: 4
-    Player 2 starting position: 8"#;
-
-        assert_eq!(part_two(example), Some(444356092776315));
+        assert_eq!(
+            part_two(EXAMPLES[0].input),
+            Ok(Answer::Integer(444356092776315))
+        );
     }
 }
@@ -5,7 +5,26 @@
 // movement and are asked to minimize their energy expenditure.
 
 // This smells like a classic implicit graph search problem, so we'll attack it with more or less Dijkstra's algorithm.
+const DAY: u32 = 23;
+
+use crate::session::{self, Command, Session};
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "\
+#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########
+",
+    answers: &[Some("12521"), Some("44169")],
+}];
+
 pub mod puzzle {
+    use super::DAY;
+    use crate::util::error::AocError;
     use std::{collections::HashMap, fmt};
 
     #[derive(Hash, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Debug)]
@@ -129,8 +148,6 @@ pub mod puzzle {
                 let destination_available = self.rooms[destination].iter().all(|state| {
                     *state == SpaceState::Empty || *state == SpaceState::Occupied(*amphipod)
                 });
-                let pathable =
-                    self.hall_pathable(hallway_pos, BurrowState::room_hall_pos(destination));
                 if destination_available
                     && self.hall_pathable(hallway_pos, BurrowState::room_hall_pos(destination))
                 {
@@ -176,6 +193,36 @@ pub mod puzzle {
             to
         }
 
+        /// The single move turning `self` into `next`, e.g. `B from room C to
+        /// hall 3`. Rooms are named after the amphipods they're for.
+        pub fn describe_move(&self, next: &BurrowState) -> String {
+            let spaces = |state: &BurrowState| {
+                let hall = state.hall.iter().enumerate();
+                let hall = hall.map(|(i, space)| (format!("hall {}", i), *space));
+                let rooms = state.rooms.iter().zip("ABCD".chars());
+                let rooms = rooms.flat_map(|(room, name)| {
+                    room.iter()
+                        .map(move |space| (format!("room {}", name), *space))
+                });
+                hall.chain(rooms).collect::<Vec<_>>()
+            };
+            let (mut mover, mut from, mut to) = (None, "?".to_string(), "?".to_string());
+            for ((label, before), (_, after)) in spaces(self).into_iter().zip(spaces(next)) {
+                match (before, after) {
+                    (SpaceState::Occupied(amphipod), SpaceState::Empty) => {
+                        mover = Some(amphipod);
+                        from = label;
+                    }
+                    (SpaceState::Empty, SpaceState::Occupied(_)) => to = label,
+                    _ => {}
+                }
+            }
+            match mover {
+                Some(amphipod) => format!("{:?} from {} to {}", amphipod, from, to),
+                None => "no move".to_string(),
+            }
+        }
+
         fn room_hall_pos(room_idx: usize) -> usize {
             room_idx * 2 + 2
         }
@@ -228,7 +275,7 @@ pub mod puzzle {
                     &SpaceState::Empty => None,
                     &SpaceState::Occupied(amphipod) => Some((amphipod, i)),
                 })
-                .filter(|(a, i)| {
+                .filter(|_| {
                     // are all the amphipods in this room in their destination?
                     // (this would include the one under investigation)
                     !self.rooms[room_idx].iter().all(|&s| match s {
@@ -330,6 +377,30 @@ pub mod puzzle {
             );
         }
 
+        /// Cheapest cost from `initial` to the goal, by a plain Dijkstra
+        /// search that doesn't rely on the heuristic
+        fn aux_exact_cost(initial: BurrowState) -> u64 {
+            use std::cmp::Reverse;
+            use std::collections::BinaryHeap;
+
+            let mut q = BinaryHeap::new();
+            let mut dist: HashMap<BurrowState, u64> = HashMap::new();
+            q.push(Reverse((0, initial)));
+            while let Some(Reverse((cost, state))) = q.pop() {
+                if state.is_goal() {
+                    return cost;
+                }
+                for (succ, cost_inc) in state.successors() {
+                    let new_cost = cost + cost_inc;
+                    if dist.get(&succ).is_none_or(|old| *old > new_cost) {
+                        dist.insert(succ.clone(), new_cost);
+                        q.push(Reverse((new_cost, succ)));
+                    }
+                }
+            }
+            panic!("no path to the goal");
+        }
+
         #[test]
         fn test_heuristic_cost() {
             let goal = r#"
@@ -338,28 +409,78 @@ pub mod puzzle {
 ###A#B#C#D###
   #A#B#C#D#
   #########
-  
             "#;
+            assert_eq!(parser::parse_input(goal).unwrap().heuristic_cost(), 0);
 
-            let example = r#"
+            // The search is only optimal if the heuristic never overestimates,
+            // so check it against the exact cost at each state the puzzle's
+            // example solution passes through
+            let solution = [
+                r#"
 #############
 #...........#
 ###B#C#B#D###
   #A#D#C#A#
   #########
-            "#;
-
-            let moved = r#"
+            "#,
+                r#"
 #############
 #...B.......#
-###.#C#B#D###
+###B#C#.#D###
   #A#D#C#A#
   #########
-            "#;
-
-            assert_eq!(parser::parse_input(goal).unwrap().heuristic_cost(), 0);
-            assert_eq!(parser::parse_input(example).unwrap().heuristic_cost(), 7369);
-            assert_eq!(parser::parse_input(moved).unwrap().heuristic_cost(), 7359);
+            "#,
+                r#"
+#############
+#...B.......#
+###B#.#C#D###
+  #A#D#C#A#
+  #########
+            "#,
+                r#"
+#############
+#.....D.....#
+###B#.#C#D###
+  #A#B#C#A#
+  #########
+            "#,
+                r#"
+#############
+#.....D.....#
+###.#B#C#D###
+  #A#B#C#A#
+  #########
+            "#,
+                r#"
+#############
+#.....D.D.A.#
+###.#B#C#.###
+  #A#B#C#.#
+  #########
+            "#,
+                r#"
+#############
+#.........A.#
+###.#B#C#D###
+  #A#B#C#D#
+  #########
+            "#,
+            ];
+            let exact: Vec<u64> = solution
+                .iter()
+                .map(|state| aux_exact_cost(parse_input(state).unwrap()))
+                .collect();
+            assert_eq!(exact, [12521, 12481, 12081, 9051, 9011, 7008, 8]);
+            for (state, exact) in solution.iter().zip(exact) {
+                let state = parse_input(state).unwrap();
+                assert!(
+                    state.heuristic_cost() <= exact,
+                    "heuristic {} over exact cost {} for\n{}",
+                    state.heuristic_cost(),
+                    exact,
+                    state
+                );
+            }
         }
 
         fn aux_test_successors_contains(input: &str, next: &str) -> usize {
@@ -416,11 +537,31 @@ pub mod puzzle {
             "#,
             ];
 
-            let mut all_successors = 0;
             for (state, desired_next) in sequence.iter().tuple_windows() {
-                all_successors += aux_test_successors_contains(state, desired_next);
+                aux_test_successors_contains(state, desired_next);
             }
-            println!("Added {} states", all_successors);
+        }
+
+        #[test]
+        fn test_parse_input_errors() {
+            let bad_room = "#############\n#...........#\n###B#C#B#D###\n  #A#E#C#A#\n  #########";
+            let e = parse_input(bad_room).unwrap_err();
+            assert_eq!((e.line, e.column), (Some(4), Some(6)));
+            assert_eq!(e.text, "E#C#A#");
+
+            let short = "#############\n#...........#\n###B#C#B###\n  #A#D#C#A#";
+            let e = parse_input(short).unwrap_err();
+            assert_eq!(
+                (e.line, e.message.as_str()),
+                (Some(3), "expected four rooms")
+            );
+
+            assert_eq!(
+                parse_input("#############\n#...........#")
+                    .unwrap_err()
+                    .line,
+                None
+            );
         }
 
         #[test]
@@ -449,7 +590,7 @@ pub mod puzzle {
                 .is_some());
         }
     }
-    mod parser {
+    pub(super) mod parser {
         use super::*;
 
         fn parse_space(input: char) -> Option<SpaceState> {
@@ -463,28 +604,63 @@ pub mod puzzle {
             }
         }
 
-        pub fn parse_input(input: &str) -> Option<BurrowState> {
-            let lines: Vec<&str> = input.trim().lines().collect();
+        pub fn parse_input(input: &str) -> Result<BurrowState, AocError> {
+            let lines: Vec<(usize, &str)> = input
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .collect();
+            if lines.len() < 4 {
+                return Err(AocError::new(
+                    DAY,
+                    "expected a burrow diagram with a hallway and two rows of rooms",
+                ));
+            }
+
+            let (index, line) = lines[1];
             let mut hallway: Vec<SpaceState> = vec![];
-            for space in lines[1].chars() {
-                if space == '.' {
-                    hallway.push(SpaceState::Empty);
-                } else if space == '#' {
-                    ()
-                } else {
-                    hallway.push(parse_space(space)?);
+            for (column, space) in line.char_indices() {
+                if space == '#' || space.is_whitespace() {
+                    continue;
                 }
+                let space = parse_space(space).ok_or_else(|| {
+                    AocError::at(
+                        DAY,
+                        index,
+                        column,
+                        &line[column..],
+                        "expected `.` or an amphipod A-D in the hallway",
+                    )
+                })?;
+                hallway.push(space);
             }
 
             // 0 becomes rooms[0][0], 4 becomes rooms[0][1], and so on.
             let mut rooms_transposed: Vec<SpaceState> = vec![];
-            for chr in (lines[2].to_owned() + lines[3]).chars() {
-                if let Some(space) = parse_space(chr) {
-                    rooms_transposed.push(space)
+            for &(index, line) in &lines[2..4] {
+                let mut row = 0;
+                for (column, space) in line.char_indices() {
+                    if space == '#' || space.is_whitespace() {
+                        continue;
+                    }
+                    let space = parse_space(space).ok_or_else(|| {
+                        AocError::at(
+                            DAY,
+                            index,
+                            column,
+                            &line[column..],
+                            "expected `.` or an amphipod A-D in a room",
+                        )
+                    })?;
+                    rooms_transposed.push(space);
+                    row += 1;
+                }
+                if row != 4 {
+                    return Err(AocError::on_line(DAY, index, line, "expected four rooms"));
                 }
             }
 
-            Some(BurrowState {
+            Ok(BurrowState {
                 rooms: vec![
                     vec![rooms_transposed[0], rooms_transposed[4]],
                     vec![rooms_transposed[1], rooms_transposed[5]],
@@ -518,28 +694,28 @@ pub mod puzzle {
         use std::cmp::Reverse;
         use std::collections::BinaryHeap;
 
+        // No decrease-key: a cheaper path to a state pushes it again, and the
+        // stale entries are skipped when they're popped
         let mut q = BinaryHeap::new();
         let mut dist: HashMap<BurrowState, u64> = HashMap::new();
         let mut counter: usize = 1;
         let mut last_counter: usize = 0;
 
+        dist.insert(initial.clone(), 0);
         q.push(Reverse((initial.heuristic_cost(), 0, initial)));
         while let Some(Reverse((_, cost, next))) = q.pop() {
+            if dist.get(&next).is_some_and(|best| *best < cost) {
+                continue;
+            }
             if next.is_goal() {
-                println!("Evaluted {} states at completion", counter);
+                crate::debug!("Evaluated {} states at completion", counter);
 
                 return Some((next, cost));
             }
 
             for (succ, cost_inc) in next.successors() {
                 let new_cost = cost + cost_inc;
-                if let Some(old_cost) = dist.get(&succ) {
-                    if *old_cost > new_cost {
-                        dist.insert(succ.clone(), new_cost);
-                        q.retain(|Reverse((_, _, s))| *s != succ);
-                        q.push(Reverse((succ.heuristic_cost() + new_cost, new_cost, succ)));
-                    }
-                } else {
+                if dist.get(&succ).is_none_or(|old_cost| *old_cost > new_cost) {
                     dist.insert(succ.clone(), new_cost);
                     q.push(Reverse((succ.heuristic_cost() + new_cost, new_cost, succ)));
                 }
@@ -547,74 +723,217 @@ pub mod puzzle {
                 counter += 1;
                 if counter - last_counter >= 100000 {
                     last_counter = counter;
-                    println!(
+                    crate::debug!(
                         "Evaluated 100k states, at {}, h(n) + g(n) = {}",
                         counter,
                         cost + next.heuristic_cost()
                     );
                     if let Some(Reverse((h, c, _))) = q.peek() {
-                        println!("Next state h(n) + g(n) = {}", h);
+                        crate::trace!("Next state h(n) + g(n) = {}", h);
                     }
-                    println!("{}", next);
+                    crate::trace!("{}", next);
                 }
             }
         }
 
         // no path found somehow
-        println!("Evaluted {} states at failure", counter);
+        crate::debug!("Evaluated {} states at failure", counter);
         return None;
     }
 
     pub mod part_one {
         use super::*;
+        use crate::solution::{Answer, Error, Result};
 
-        pub fn run(input: &str) -> Option<u64> {
+        pub fn run(input: &str) -> Result<Answer> {
             let initial = parser::parse_input(input)?;
-            let (_, energy) = search(initial)?;
-            Some(energy)
+            let (_, energy) =
+                search(initial).ok_or_else(|| Error::no_answer("amphipods can't be organized"))?;
+            Ok(energy.into())
         }
     }
 
     pub mod part_two {
         use super::*;
+        use crate::solution::{Answer, Error, Result};
 
-        pub fn run(input: &str) -> Option<u64> {
+        pub fn run(input: &str) -> Result<Answer> {
             let mut initial = parser::parse_input(input)?;
             parser::mangle_to_part_two(&mut initial);
-            println!("Initial\n {}", initial);
-            let (_, energy) = search(initial)?;
-            Some(energy)
+            crate::debug!("Initial\n {}", initial);
+            let (_, energy) =
+                search(initial).ok_or_else(|| Error::no_answer("amphipods can't be organized"))?;
+            Ok(energy.into())
         }
     }
 }
 
+/// Walks the burrow one move at a time. Each picked state is kept so moves
+/// can be undone.
+struct BurrowSession {
+    path: Vec<(puzzle::BurrowState, u64)>,
+}
+impl BurrowSession {
+    fn current(&self) -> &(puzzle::BurrowState, u64) {
+        self.path
+            .last()
+            .expect("the path starts at the initial state")
+    }
+}
+impl Session for BurrowSession {
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                name: "moves",
+                args: "",
+                help: "list the moves from here, numbered, with their energy",
+            },
+            Command {
+                name: "pick",
+                args: "<N>",
+                help: "make move N of the list",
+            },
+            Command {
+                name: "undo",
+                args: "",
+                help: "take back the last move",
+            },
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> std::result::Result<String, String> {
+        let (state, energy) = self.current().clone();
+        match command {
+            "moves" => Ok(state
+                .successors()
+                .iter()
+                .enumerate()
+                .map(|(i, (next, cost))| {
+                    format!("{}: {} ({})", i + 1, state.describe_move(next), cost)
+                })
+                .collect::<Vec<String>>()
+                .join("\n")),
+            "pick" => {
+                let moves = state.successors();
+                let picked = match args {
+                    [n] => n
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|n| moves.get(n.checked_sub(1)?)),
+                    _ => None,
+                };
+                let (next, cost) = picked
+                    .ok_or_else(|| format!("pick expects a move from 1 to {}", moves.len()))?;
+                self.path.push((next.clone(), energy + cost));
+                Ok(self.show())
+            }
+            "undo" => {
+                if self.path.len() == 1 {
+                    return Err("nothing to undo".to_string());
+                }
+                self.path.pop();
+                Ok(self.show())
+            }
+            _ => Err(session::unknown(command)),
+        }
+    }
+
+    fn show(&self) -> String {
+        let (state, energy) = self.current();
+        let status = if state.is_goal() {
+            "organized".to_string()
+        } else {
+            format!("at least {} to go", state.heuristic_cost())
+        };
+        format!(
+            "{} energy spent, {}\n{}",
+            energy,
+            status,
+            state.to_string().trim_end()
+        )
+    }
+}
+
+pub struct Day23;
+impl crate::solution::Solution for Day23 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![puzzle::part_one::run, puzzle::part_two::run]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| {
+            puzzle::parser::parse_input(input)
+                .map(drop)
+                .map_err(crate::solution::Error::from)
+        })
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn session(&self) -> Option<session::Start> {
+        Some(|input| {
+            let initial = puzzle::parser::parse_input(input)?;
+            Ok(Box::new(BurrowSession {
+                path: vec![(initial, 0)],
+            }))
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::Answer;
 
     #[test]
     fn test_part_one_example() {
-        let example = r#"
-#############
-#...........#
-###B#C#B#D###
-  #A#D#C#A#
-  #########
-        "#;
-
-        assert_eq!(puzzle::part_one::run(example), Some(12521))
+        assert_eq!(
+            puzzle::part_one::run(EXAMPLES[0].input),
+            Ok(Answer::Integer(12521))
+        )
     }
 
     #[test]
     fn test_part_two_example() {
-        let example = r#"
-#############
-#...........#
-###B#C#B#D###
-  #A#D#C#A#
-  #########
-        "#;
+        assert_eq!(
+            puzzle::part_two::run(EXAMPLES[0].input),
+            Ok(Answer::Integer(44169))
+        )
+    }
 
-        assert_eq!(puzzle::part_two::run(example), Some(44169))
+    #[test]
+    fn test_session() {
+        use crate::solution::Solution;
+
+        let start = Day23.session().unwrap();
+        let mut session = start(EXAMPLES[0].input).unwrap();
+        let moves = session.run("moves", &[]).unwrap();
+        assert_eq!(moves.lines().count(), 28);
+        assert_eq!(
+            moves.lines().nth(2),
+            Some("3: B from room A to hall 3 (20)")
+        );
+
+        let picked = session.run("pick", &["3"]).unwrap();
+        assert!(picked.starts_with("20 energy spent, at least "));
+        assert_eq!(picked.lines().nth(2), Some("#...B.......#"));
+        assert!(session.run("pick", &["29"]).is_err());
+        assert!(session.run("pick", &["0"]).is_err());
+
+        assert!(session
+            .run("undo", &[])
+            .unwrap()
+            .starts_with("0 energy spent"));
+        assert!(session.run("undo", &[]).is_err());
     }
 }
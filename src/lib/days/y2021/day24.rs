@@ -1,4 +1,8 @@
+const DAY: u32 = 24;
+
 pub mod puzzle {
+    use super::DAY;
+    use crate::util::log;
     use itertools::Itertools;
 
     #[derive(Debug, Clone, PartialEq, Eq)]
@@ -46,29 +50,46 @@ pub mod puzzle {
             }
         }
 
+        fn operand(&mut self, r: &RightHandOperand) -> i64 {
+            match r {
+                RightHandOperand::Var(v) => *self.select_reg(v),
+                RightHandOperand::Number(n) => *n,
+            }
+        }
+
         fn binop<F>(&mut self, l: &Var, r: &RightHandOperand, f: F)
         where
             F: Fn(i64, i64) -> i64,
         {
             let orig = *self.select_reg(l);
-            let rhs: i64 = match r {
-                RightHandOperand::Var(v) => *self.select_reg(v),
-                RightHandOperand::Number(n) => *n,
-            };
+            let rhs = self.operand(r);
             *self.select_reg(l) = f(orig, rhs);
         }
-        pub fn run(&mut self, instrs: &[Instruction], mut digits: Vec<i64>) {
+
+        /// Fails if the program reads more digits than given, or divides or
+        /// takes a modulus by zero
+        pub fn run(
+            &mut self,
+            instrs: &[Instruction],
+            mut digits: Vec<i64>,
+        ) -> Result<(), &'static str> {
             digits.reverse();
             for instr in instrs {
                 match instr {
-                    Instruction::Input(l) => *self.select_reg(l) = digits.pop().unwrap(),
+                    Instruction::Input(l) => {
+                        *self.select_reg(l) = digits.pop().ok_or("ran out of input digits")?
+                    }
                     Instruction::Add(l, r) => self.binop(l, r, |a, b| a + b),
                     Instruction::Mul(l, r) => self.binop(l, r, |a, b| a * b),
+                    Instruction::Div(_, r) | Instruction::Mod(_, r) if self.operand(r) == 0 => {
+                        return Err("divisor or modulus of zero")
+                    }
                     Instruction::Div(l, r) => self.binop(l, r, |a, b| a / b),
                     Instruction::Mod(l, r) => self.binop(l, r, |a, b| a % b),
                     Instruction::Eql(l, r) => self.binop(l, r, |a, b| if a == b { 1 } else { 0 }),
                 }
             }
+            Ok(())
         }
     }
 
@@ -94,6 +115,9 @@ pub mod puzzle {
 
         for (i, SectionParam { a, b, c }) in params.iter().enumerate() {
             z = emulate_section(*a, *b, *c, digits[i], z);
+            if !log::enabled(log::Level::Trace) {
+                continue;
+            }
             let mut b26 = vec![];
             let mut working_z = z;
             while working_z != 0 {
@@ -102,7 +126,7 @@ pub mod puzzle {
             }
             b26.reverse();
             let b26s = b26.iter().map(|e| e.to_string()).join(",");
-            println!(
+            crate::trace!(
                 "{}: Emulated z = {} ({},{},{}) d={}, d'={}",
                 i,
                 b26s,
@@ -119,12 +143,12 @@ pub mod puzzle {
 
     #[cfg(test)]
     mod tests {
-        use super::{emulate_section, parser::parse_input, ALU};
+        use super::{emulate_section, parser::instructions, ALU};
 
         fn aux_run(input: &str, digits: Vec<i64>) -> i64 {
-            let instrs = parse_input(input).unwrap();
+            let (_, instrs) = instructions(input).unwrap();
             let mut alu = ALU::new();
-            alu.run(&instrs, digits);
+            alu.run(&instrs, digits).unwrap();
 
             alu.z
         }
@@ -890,7 +914,7 @@ add z y
 
         #[cfg(test)]
         mod tests {
-            use crate::day24::puzzle::parser;
+            use crate::y2021::day24::puzzle::parser;
 
             use super::*;
 
@@ -899,7 +923,7 @@ add z y
             }
 
             fn aux_eval(input: &str) -> (Vec<Instruction>, Bindings) {
-                let straightline = parser::parse_input(input).unwrap();
+                let (_, straightline) = parser::instructions(input).unwrap();
                 let (ssa, index_state) = from_straightline(&straightline);
                 eliminate_constants(&ssa, index_state)
             }
@@ -1085,6 +1109,7 @@ add z y
         }
     }
     pub mod parser {
+        use crate::util::error::AocError;
         use crate::util::nom_helpers::ws;
         use itertools::Itertools;
         use nom::{
@@ -1099,6 +1124,9 @@ add z y
         use super::*;
         use Instruction::*;
 
+        // MONAD checks one digit of the model number per section
+        const SECTIONS: usize = 14;
+
         pub fn var(input: &str) -> IResult<&str, Var> {
             alt((
                 value(Var::X, tag("x")),
@@ -1136,9 +1164,35 @@ add z y
             many1(ws(instruction))(input)
         }
 
-        pub fn parse_input(input: &str) -> Option<Vec<Instruction>> {
-            let (_, instrs) = instructions(input).ok()?;
-            Some(instrs)
+        pub fn parse_input(input: &str) -> Result<Vec<Instruction>, AocError> {
+            let expected = "expected an instruction like `add x 1` or `inp w`";
+            let (rest, instrs) =
+                instructions(input).map_err(|e| AocError::from_nom(DAY, input, e, expected))?;
+            if !rest.trim().is_empty() {
+                let offset = input.len() - rest.trim_start().len();
+                return Err(AocError::at_offset(DAY, input, offset, expected));
+            }
+
+            if !matches!(instrs.first(), Some(Input(_))) {
+                let offset = input.len() - input.trim_start().len();
+                return Err(AocError::at_offset(
+                    DAY,
+                    input,
+                    offset,
+                    "expected the program to start with `inp`",
+                ));
+            }
+            let sections = instrs.iter().filter(|i| matches!(i, Input(_))).count();
+            if sections != SECTIONS {
+                return Err(AocError::new(
+                    DAY,
+                    &format!(
+                        "expected {} sections each starting with `inp`, found {}",
+                        SECTIONS, sections
+                    ),
+                ));
+            }
+            Ok(instrs)
         }
 
         fn get_constant(input: &str) -> Option<i64> {
@@ -1168,6 +1222,91 @@ add z y
                 .collect()
         }
     }
+
+    use crate::solution::{Answer, Error, Result};
+
+    // Sections with a == 1 push d + c onto the base 26 "stack" in z, sections
+    // with a == 26 pop it again, but only if d == popped + b. z ends at 0 iff
+    // every pop succeeds, which pairs up the digits as d_pop = d_push + c + b.
+    // `choose` picks the push digit given the offset between the pair.
+    fn model_number<F>(params: &[SectionParam], choose: F) -> Option<Vec<i64>>
+    where
+        F: Fn(i64) -> i64,
+    {
+        let mut digits = vec![0; params.len()];
+        let mut stack: Vec<(usize, i64)> = vec![];
+        for (i, param) in params.iter().enumerate() {
+            if param.a == 1 {
+                stack.push((i, param.c));
+            } else {
+                let (j, c) = stack.pop()?;
+                let offset = c + param.b;
+                digits[j] = choose(offset);
+                digits[i] = digits[j] + offset;
+            }
+        }
+        if stack.is_empty() {
+            Some(digits)
+        } else {
+            None
+        }
+    }
+
+    fn validated_model_number<F>(input: &str, choose: F) -> Result<Answer>
+    where
+        F: Fn(i64) -> i64,
+    {
+        let instrs = parser::parse_input(input)?;
+        let params = parser::extract_params(input);
+        let digits = model_number(&params, choose)
+            .ok_or_else(|| Error::no_answer("MONAD sections don't pair up"))?;
+
+        let mut alu = ALU::new();
+        alu.run(&instrs, digits.clone()).map_err(Error::no_answer)?;
+        if alu.z != 0 {
+            return Err(Error::no_answer("model number rejected by MONAD"));
+        }
+        Ok(digits.iter().fold(0, |acc, d| acc * 10 + *d as u64).into())
+    }
+
+    pub mod part_one {
+        use super::*;
+
+        pub fn run(input: &str) -> Result<Answer> {
+            validated_model_number(input, |offset| std::cmp::min(9, 9 - offset))
+        }
+    }
+
+    pub mod part_two {
+        use super::*;
+
+        pub fn run(input: &str) -> Result<Answer> {
+            validated_model_number(input, |offset| std::cmp::max(1, 1 - offset))
+        }
+    }
+}
+
+pub struct Day24;
+impl crate::solution::Solution for Day24 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![puzzle::part_one::run, puzzle::part_two::run]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| {
+            puzzle::parser::parse_input(input)
+                .map(drop)
+                .map_err(crate::solution::Error::from)
+        })
+    }
 }
 
 #[cfg(test)]
@@ -1200,4 +1339,45 @@ add z y
         let (_, instrs) = parser::instructions(example).unwrap();
         assert_eq!(instrs.len(), 18)
     }
+
+    #[test]
+    fn test_alu_errors() {
+        let (_, instrs) = parser::instructions("inp w\ninp x").unwrap();
+        assert_eq!(
+            ALU::new().run(&instrs, vec![1]),
+            Err("ran out of input digits")
+        );
+
+        let (_, instrs) = parser::instructions("inp w\ndiv w x").unwrap();
+        assert_eq!(
+            ALU::new().run(&instrs, vec![1]),
+            Err("divisor or modulus of zero")
+        );
+        let (_, instrs) = parser::instructions("inp w\nmod w 0").unwrap();
+        assert_eq!(
+            ALU::new().run(&instrs, vec![1]),
+            Err("divisor or modulus of zero")
+        );
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let e = parser::parse_input("inp w\nadd x\nmul y 0\n")
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.column), (Some(2), Some(1)));
+        assert_eq!(e.text, "add x");
+
+        let e = parser::parse_input("jmp 3").err().unwrap();
+        assert_eq!((e.line, e.column), (Some(1), Some(1)));
+
+        let e = parser::parse_input("inp w").err().unwrap();
+        assert_eq!(
+            e.message,
+            "expected 14 sections each starting with `inp`, found 1"
+        );
+
+        let e = parser::parse_input("\nadd x 1\ninp w").err().unwrap();
+        assert_eq!((e.line, e.column), (Some(2), Some(1)));
+    }
 }
@@ -0,0 +1,250 @@
+const DAY: u32 = 25;
+
+use crate::solution::Example;
+
+/// Examples from the puzzle description
+pub const EXAMPLES: &[Example] = &[Example {
+    input: "\
+v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>
+",
+    answers: &[Some("58")],
+}];
+
+use crate::session::{self, Command, Session};
+use crate::util::grid::Grid;
+
+pub mod puzzle {
+    use super::DAY;
+    use crate::util::grid::Grid;
+    use crate::util::pos::Dir;
+    use std::fmt::Display;
+
+    #[derive(Clone, PartialEq)]
+    pub enum Occupancy {
+        East,
+        South,
+        Empty,
+    }
+    impl Occupancy {
+        pub fn from_char(c: char) -> Option<Occupancy> {
+            match c {
+                '>' => Some(Occupancy::East),
+                'v' => Some(Occupancy::South),
+                '.' => Some(Occupancy::Empty),
+                _ => None,
+            }
+        }
+    }
+    impl Display for Occupancy {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Occupancy::East => f.write_str(">"),
+                Occupancy::South => f.write_str("v"),
+                Occupancy::Empty => f.write_str("."),
+            }
+        }
+    }
+
+    /// Move every cucumber of `herd` that has room one step towards `dir`,
+    /// simultaneously and wrapping around the edges. This runs for every
+    /// cell on every step, so it walks rows and columns directly rather than
+    /// paying for `Grid::wrapped_step` and a checked `Pos` lookup per cell.
+    fn advance_herd(map: &mut Grid<Occupancy>, herd: Occupancy, dir: Dir) -> bool {
+        let (height, width) = (map.height(), map.width());
+        let offset = dir.offset();
+        let wrap = |n: usize, dn: i32, len: usize| match dn {
+            1 if n + 1 == len => 0,
+            1 => n + 1,
+            -1 if n == 0 => len - 1,
+            -1 => n - 1,
+            _ => n,
+        };
+        let mut moves = vec![];
+        for row in 0..height {
+            for col in 0..width {
+                if map[(row, col)] != herd {
+                    continue;
+                }
+                let to = (wrap(row, offset.row, height), wrap(col, offset.col, width));
+                if map[to] == Occupancy::Empty {
+                    moves.push(((row, col), to));
+                }
+            }
+        }
+        for &(from, to) in &moves {
+            map[from] = Occupancy::Empty;
+            map[to] = herd.clone();
+        }
+        !moves.is_empty()
+    }
+
+    pub fn advance_herds(map: &mut Grid<Occupancy>) -> bool {
+        // East moves, simultaneously
+        // South moves, simultaneously
+        let east_moved = advance_herd(map, Occupancy::East, Dir::E);
+        let south_moved = advance_herd(map, Occupancy::South, Dir::S);
+        east_moved || south_moved
+    }
+
+    pub(super) mod parser {
+        use super::*;
+        use crate::util::error::AocError;
+
+        pub fn parse_input(input: &str) -> Result<Grid<Occupancy>, AocError> {
+            Grid::parse_with(DAY, input, |c| {
+                Occupancy::from_char(c).ok_or("expected `>`, `v` or `.`")
+            })
+        }
+    }
+
+    pub mod part_one {
+        use super::{advance_herds, parser};
+        use crate::solution::{Answer, Result};
+
+        pub fn run(input: &str) -> Result<Answer> {
+            let mut map = parser::parse_input(input)?;
+            let mut moves: u64 = 0;
+            while advance_herds(&mut map) {
+                moves += 1;
+            }
+            Ok((moves + 1).into())
+        }
+    }
+}
+
+/// Moves the herds by hand, to watch them jam up
+struct HerdSession {
+    map: Grid<puzzle::Occupancy>,
+    steps: u64,
+    settled: bool,
+}
+impl Session for HerdSession {
+    fn commands(&self) -> &'static [Command] {
+        &[Command {
+            name: "step",
+            args: "[N]",
+            help: "move the herds N times, 1 by default, stopping once they settle",
+        }]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> std::result::Result<String, String> {
+        match command {
+            "step" => {
+                for _ in 0..session::count(args)? {
+                    if self.settled {
+                        break;
+                    }
+                    self.steps += 1;
+                    self.settled = !puzzle::advance_herds(&mut self.map);
+                }
+                Ok(self.show())
+            }
+            _ => Err(session::unknown(command)),
+        }
+    }
+
+    fn show(&self) -> String {
+        let status = if self.settled { ", settled" } else { "" };
+        format!(
+            "after step {}{}\n{}",
+            self.steps,
+            status,
+            self.map.to_string().trim_end()
+        )
+    }
+}
+
+pub struct Day25;
+impl crate::solution::Solution for Day25 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parts(&self) -> Vec<crate::solution::Entry> {
+        vec![puzzle::part_one::run]
+    }
+
+    fn parse(&self) -> Option<crate::solution::Parse> {
+        Some(|input| {
+            puzzle::parser::parse_input(input)
+                .map(drop)
+                .map_err(crate::solution::Error::from)
+        })
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn session(&self) -> Option<session::Start> {
+        Some(|input| {
+            let map = puzzle::parser::parse_input(input)?;
+            Ok(Box::new(HerdSession {
+                map,
+                steps: 0,
+                settled: false,
+            }))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::puzzle::*;
+    use super::EXAMPLES;
+    use crate::solution::Answer;
+
+    #[test]
+    fn test_day_one_examples() {
+        assert_eq!(part_one::run(EXAMPLES[0].input), Ok(Answer::Integer(58)));
+    }
+
+    #[test]
+    fn test_session() {
+        use crate::solution::Solution;
+
+        let start = super::Day25.session().unwrap();
+        let mut session =
+            start("...>...\n.......\n......>\nv.....>\n......>\n.......\n..vvv..\n").unwrap();
+        assert_eq!(
+            session.run("step", &[]),
+            Ok(
+                "after step 1\n..vv>..\n.......\n>......\nv.....>\n>......\n.......\n....v.."
+                    .to_string()
+            )
+        );
+        assert!(session.run("step", &["-"]).is_err());
+
+        let mut session = start(EXAMPLES[0].input).unwrap();
+        assert!(session
+            .run("step", &["100"])
+            .unwrap()
+            .starts_with("after step 58, settled\n"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = part_one::run("v..>\n.x..\n").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            r#"day 25, line 2, column 2: expected `>`, `v` or `.`, found "x""#
+        );
+        let e = part_one::run("v..>\n.v.\n").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            r#"day 25, line 2, column 1: rows differ in length, found ".v.""#
+        );
+    }
+}
@@ -0,0 +1,73 @@
+// Advent of Code 2021
+
+pub const YEAR: u32 = 2021;
+
+#[cfg(feature = "y2021-day11")]
+pub mod day11;
+#[cfg(feature = "y2021-day12")]
+pub mod day12;
+#[cfg(feature = "y2021-day13")]
+pub mod day13;
+#[cfg(feature = "y2021-day14")]
+pub mod day14;
+#[cfg(feature = "y2021-day15")]
+pub mod day15;
+#[cfg(feature = "y2021-day16")]
+pub mod day16;
+#[cfg(feature = "y2021-day17")]
+pub mod day17;
+#[cfg(feature = "y2021-day18")]
+pub mod day18;
+#[cfg(feature = "y2021-day19")]
+pub mod day19;
+#[cfg(feature = "y2021-day20")]
+pub mod day20;
+#[cfg(feature = "y2021-day21")]
+pub mod day21;
+#[cfg(feature = "y2021-day22")]
+pub mod day22;
+#[cfg(feature = "y2021-day23")]
+pub mod day23;
+#[cfg(feature = "y2021-day24")]
+pub mod day24;
+#[cfg(feature = "y2021-day25")]
+pub mod day25;
+
+use crate::solution::Solution;
+
+/// This year's solutions compiled in, ordered by day
+pub fn solutions() -> Vec<&'static dyn Solution> {
+    let solutions: &[&'static dyn Solution] = &[
+        #[cfg(feature = "y2021-day11")]
+        &day11::Day11,
+        #[cfg(feature = "y2021-day12")]
+        &day12::Day12,
+        #[cfg(feature = "y2021-day13")]
+        &day13::Day13,
+        #[cfg(feature = "y2021-day14")]
+        &day14::Day14,
+        #[cfg(feature = "y2021-day15")]
+        &day15::Day15,
+        #[cfg(feature = "y2021-day16")]
+        &day16::Day16,
+        #[cfg(feature = "y2021-day17")]
+        &day17::Day17,
+        #[cfg(feature = "y2021-day18")]
+        &day18::Day18,
+        #[cfg(feature = "y2021-day19")]
+        &day19::Day19,
+        #[cfg(feature = "y2021-day20")]
+        &day20::Day20,
+        #[cfg(feature = "y2021-day21")]
+        &day21::Day21,
+        #[cfg(feature = "y2021-day22")]
+        &day22::Day22,
+        #[cfg(feature = "y2021-day23")]
+        &day23::Day23,
+        #[cfg(feature = "y2021-day24")]
+        &day24::Day24,
+        #[cfg(feature = "y2021-day25")]
+        &day25::Day25,
+    ];
+    solutions.to_vec()
+}